@@ -0,0 +1,297 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    astronomy,
+    date::{self, Date, DateError, DateResult, FrcDate, EPOCH_JDN},
+    delta_t::Calibrated,
+};
+
+/// First year supported. The solar theory is a polynomial in time that loses
+/// its accuracy millennia before, but every year from here to [`MAX_YEAR`] is
+/// checked to last 365 or 366 days.
+pub(crate) const MIN_YEAR: i32 = -20_000;
+
+/// Last year supported
+pub(crate) const MAX_YEAR: i32 = 20_000;
+
+/// Represents an FRC date following the rule of the original decree: each
+/// year starts on the day, in Paris mean time, containing the autumn equinox
+/// at the Paris Observatory.
+///
+/// Unlike [`QDate`](crate::QDate), which looks the equinoxes up in a table,
+/// this computes them from the position of the Sun, so it is slower but
+/// reaches further, from An -20000 to An 20000. ΔT follows the
+/// [`Calibrated`] model, with which every year of the table starts on the
+/// same day as in [`QDate`](crate::QDate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AstronomicalDate {
+    jdn: i64,
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Display for AstronomicalDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Date::new(*self).fmt(f)
+    }
+}
+
+impl FromStr for AstronomicalDate {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> DateResult<AstronomicalDate> {
+        s.parse::<Date<Self>>().map(Date::into_inner)
+    }
+}
+
+impl FrcDate for AstronomicalDate {
+    /// 1 Vendémiaire An -20000
+    const MIN_JULIAN_DAY: i64 = -4_929_389;
+    /// Last day of An 20000
+    const MAX_JULIAN_DAY: i64 = 9_680_667;
+
+    fn month_int(&self) -> u8 {
+        self.month
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns whether `year` is a leap year, i.e. whether 366 days separate
+    /// its equinox from the next. Years outside of the supported range are
+    /// reported as regular years.
+    fn leap_year(year: i32) -> bool {
+        AstronomicalDate::year_span(year).is_ok_and(|(start, end)| end - start == 366)
+    }
+
+    fn julian_day(&self) -> i64 {
+        self.jdn
+    }
+
+    fn from_julian_day(jdn: i64) -> DateResult<Self> {
+        if !(Self::MIN_JULIAN_DAY..=Self::MAX_JULIAN_DAY).contains(&jdn) {
+            return Err(date::out_of_range::<Self>());
+        }
+
+        let mut year =
+            (((jdn - EPOCH_JDN) as f64 / 365.242_2).floor() as i32 + 1).clamp(MIN_YEAR, MAX_YEAR);
+
+        while AstronomicalDate::new_year_day(year) > jdn {
+            year -= 1;
+        }
+
+        while AstronomicalDate::new_year_day(year + 1) <= jdn {
+            year += 1;
+        }
+
+        let (start, _) = AstronomicalDate::year_span(year)?;
+        let day_of_year = jdn - start;
+
+        Ok(Self {
+            jdn,
+            year,
+            month: (day_of_year / 30 + 1) as u8,
+            day: (day_of_year % 30 + 1) as u8,
+        })
+    }
+
+    fn from_frc(year: i32, month: u8, day: u8) -> DateResult<Self> {
+        let (start, end) = AstronomicalDate::year_span(year)?;
+
+        if !date::is_valid_day(month, day, end - start == 366) {
+            return Err(DateError::InvalidDate);
+        }
+
+        let day_of_year = (i64::from(month) - 1) * 30 + i64::from(day) - 1;
+
+        Ok(Self {
+            jdn: start + day_of_year,
+            year,
+            month,
+            day,
+        })
+    }
+}
+
+date::impl_day_arithmetic!(AstronomicalDate);
+
+impl AstronomicalDate {
+    pub fn from_gregorian_date(year: i32, month: u8, day: u8) -> DateResult<Self> {
+        let month = time::Month::try_from(month).map_err(|_| DateError::InvalidDate)?;
+        let date = time::Date::from_calendar_date(year, month, day)?;
+
+        AstronomicalDate::from_julian_day(date.to_julian_day().into())
+    }
+
+    /// Returns the Gregorian date corresponding to this date.
+    pub fn to_gregorian(&self) -> Result<time::Date, DateError> {
+        let jdn = i32::try_from(self.jdn).map_err(|_| DateError::GregorianOutOfRange)?;
+
+        time::Date::from_julian_day(jdn).map_err(|_| DateError::GregorianOutOfRange)
+    }
+
+    /// Returns the Julian Day Number of 1 Vendémiaire of `year`, which must
+    /// lie between [`MIN_YEAR`] and the year after [`MAX_YEAR`].
+    fn new_year_day(year: i32) -> i64 {
+        debug_assert!((MIN_YEAR..=MAX_YEAR + 1).contains(&year));
+
+        astronomy::equinox_day(year + 1791, &Calibrated)
+    }
+
+    /// Returns the Julian Day Numbers of 1 Vendémiaire of `year` and of the
+    /// following year, or an error when `year` is outside of the supported
+    /// range or would not last 365 or 366 days.
+    fn year_span(year: i32) -> DateResult<(i64, i64)> {
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+            return Err(date::out_of_range::<Self>());
+        }
+
+        let start = AstronomicalDate::new_year_day(year);
+        let end = AstronomicalDate::new_year_day(year + 1);
+
+        if !(365..=366).contains(&(end - start)) {
+            return Err(date::out_of_range::<Self>());
+        }
+
+        Ok((start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AstronomicalDate, MAX_YEAR, MIN_YEAR};
+    use crate::{
+        cal,
+        date::{DateError, FrcDate},
+        QDate,
+    };
+
+    #[test]
+    fn matches_equinox_table() {
+        for index in 0..cal::LEAP_YEARS.len() {
+            let year = cal::START_YEAR + index as i32;
+            let expected = QDate::from_frc(year, 1, 1).unwrap().julian_day();
+
+            assert_eq!(
+                AstronomicalDate::new_year_day(year),
+                expected,
+                "year {}",
+                year
+            );
+            assert_eq!(
+                AstronomicalDate::leap_year(year),
+                QDate::leap_year(year),
+                "year {}",
+                year
+            );
+        }
+    }
+
+    #[test]
+    fn year_lengths() {
+        let mut start = AstronomicalDate::new_year_day(MIN_YEAR);
+
+        for year in MIN_YEAR..=MAX_YEAR {
+            let end = AstronomicalDate::new_year_day(year + 1);
+
+            assert!((365..=366).contains(&(end - start)), "year {}", year);
+            start = end;
+        }
+    }
+
+    #[test]
+    fn conversion() {
+        let dates = vec![
+            ((1, 1, 1), (1792, 9, 22)),
+            ((3, 13, 6), (1795, 9, 22)),
+            ((4, 1, 1), (1795, 9, 23)),
+            ((8, 2, 18), (1799, 11, 9)),
+            ((233, 1, 1), (2024, 9, 22)),
+            ((233, 2, 24), (2024, 11, 14)),
+        ];
+
+        for ((year, month, day), (gregorian_year, gregorian_month, gregorian_day)) in dates {
+            let date = AstronomicalDate::from_frc(year, month, day).unwrap();
+            let from_gregorian = AstronomicalDate::from_gregorian_date(
+                gregorian_year,
+                gregorian_month,
+                gregorian_day,
+            )
+            .unwrap();
+
+            assert_eq!(date, from_gregorian);
+            assert_eq!(
+                (
+                    from_gregorian.year(),
+                    from_gregorian.month_int(),
+                    from_gregorian.day()
+                ),
+                (year, month, day)
+            );
+        }
+
+        let mut date = AstronomicalDate::from_frc(230, 1, 1).unwrap();
+        let mut qdate = QDate::from_frc(230, 1, 1).unwrap();
+
+        for _ in 0..2_000 {
+            assert_eq!(
+                (date.year(), date.month_int(), date.day()),
+                (qdate.year(), qdate.month_int(), qdate.day())
+            );
+
+            date = date.succ().unwrap();
+            qdate = qdate.succ().unwrap();
+        }
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(
+            AstronomicalDate::MIN_JULIAN_DAY,
+            AstronomicalDate::new_year_day(MIN_YEAR)
+        );
+        assert_eq!(
+            AstronomicalDate::MAX_JULIAN_DAY,
+            AstronomicalDate::new_year_day(MAX_YEAR + 1) - 1
+        );
+
+        let last = AstronomicalDate::from_julian_day(AstronomicalDate::MAX_JULIAN_DAY).unwrap();
+        assert_eq!(last.year(), MAX_YEAR);
+        assert_eq!(last.month_int(), 13);
+        assert!(matches!(last.succ(), Err(DateError::OutOfRange { .. })));
+
+        let first = AstronomicalDate::from_julian_day(AstronomicalDate::MIN_JULIAN_DAY).unwrap();
+        assert_eq!(
+            (first.year(), first.month_int(), first.day()),
+            (MIN_YEAR, 1, 1)
+        );
+        assert!(matches!(first.pred(), Err(DateError::OutOfRange { .. })));
+
+        let date = first.checked_add_days(400).unwrap();
+        assert_eq!((date.year(), date.month_int()), (MIN_YEAR + 1, 2));
+
+        assert!(matches!(
+            AstronomicalDate::from_frc(MAX_YEAR + 1, 1, 1),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            AstronomicalDate::from_frc(MIN_YEAR - 1, 13, 5),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            AstronomicalDate::from_frc(i32::MAX, 1, 1),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(!AstronomicalDate::leap_year(i32::MIN));
+        assert!(matches!(
+            AstronomicalDate::from_frc(233, 13, 7),
+            Err(DateError::InvalidDate)
+        ));
+    }
+}
@@ -0,0 +1,284 @@
+//! Position of the Sun and the autumn equinox, following Jean Meeus,
+//! _Astronomical Algorithms_ (2nd ed.), chapters 22, 25 and 27, with the
+//! truncated VSOP87 theory of the Earth from his appendix III.
+
+use std::f64::consts::PI;
+
+use crate::delta_t::DeltaT;
+
+/// Julian Ephemeris Day of J2000.0
+const J2000: f64 = 2_451_545.0;
+
+/// Days in a Julian millennium
+const DAYS_PER_MILLENNIUM: f64 = 365_250.0;
+
+/// Offset of Paris mean time from UT in seconds, the 9 min 21 s by which the
+/// Paris Observatory meridian lies east of Greenwich.
+pub(crate) const PARIS_MEAN_TIME_OFFSET: i32 = 9 * 60 + 21;
+
+/// A term `a * cos(b + c * τ)` of a VSOP87 series.
+type Term = (f64, f64, f64);
+
+const L0: [Term; 64] = [
+    (175_347_046.0, 0.0, 0.0),
+    (3_341_656.0, 4.669_256_8, 6_283.075_85),
+    (34_894.0, 4.626_1, 12_566.151_7),
+    (3_497.0, 2.744_1, 5_753.384_9),
+    (3_418.0, 2.828_9, 3.523_1),
+    (3_136.0, 3.627_7, 77_713.771_5),
+    (2_676.0, 4.418_1, 7_860.419_4),
+    (2_343.0, 6.135_2, 3_930.209_7),
+    (1_324.0, 0.742_5, 11_506.769_8),
+    (1_273.0, 2.037_1, 529.691),
+    (1_199.0, 1.109_6, 1_577.343_5),
+    (990.0, 5.233, 5_884.927),
+    (902.0, 2.045, 26.298),
+    (857.0, 3.508, 398.149),
+    (780.0, 1.179, 5_223.694),
+    (753.0, 2.533, 5_507.553),
+    (505.0, 4.583, 18_849.228),
+    (492.0, 4.205, 775.523),
+    (357.0, 2.92, 0.067),
+    (317.0, 5.849, 11_790.629),
+    (284.0, 1.899, 796.298),
+    (271.0, 0.315, 10_977.079),
+    (243.0, 0.345, 5_486.778),
+    (206.0, 4.806, 2_544.314),
+    (205.0, 1.869, 5_573.143),
+    (202.0, 2.458, 6_069.777),
+    (156.0, 0.833, 213.299),
+    (132.0, 3.411, 2_942.463),
+    (126.0, 1.083, 20.775),
+    (115.0, 0.645, 0.98),
+    (103.0, 0.636, 4_694.003),
+    (102.0, 0.976, 15_720.839),
+    (102.0, 4.267, 7.114),
+    (99.0, 6.21, 2_146.17),
+    (98.0, 0.68, 155.42),
+    (86.0, 5.98, 161_000.69),
+    (85.0, 1.3, 6_275.96),
+    (85.0, 3.67, 71_430.7),
+    (80.0, 1.81, 17_260.15),
+    (79.0, 3.04, 12_036.46),
+    (75.0, 1.76, 5_088.63),
+    (74.0, 3.5, 3_154.69),
+    (74.0, 4.68, 801.82),
+    (70.0, 0.83, 9_437.76),
+    (62.0, 3.98, 8_827.39),
+    (61.0, 1.82, 7_084.9),
+    (57.0, 2.78, 6_286.6),
+    (56.0, 4.39, 14_143.5),
+    (56.0, 3.47, 6_279.55),
+    (52.0, 0.19, 12_139.55),
+    (52.0, 1.33, 1_748.02),
+    (51.0, 0.28, 5_856.48),
+    (49.0, 0.49, 1_194.45),
+    (41.0, 5.37, 8_429.24),
+    (41.0, 2.4, 19_651.05),
+    (39.0, 6.17, 10_447.39),
+    (37.0, 6.04, 10_213.29),
+    (37.0, 2.57, 1_059.38),
+    (36.0, 1.71, 2_352.87),
+    (36.0, 1.78, 6_812.77),
+    (33.0, 0.59, 17_789.85),
+    (30.0, 0.44, 83_996.85),
+    (30.0, 2.74, 1_349.87),
+    (25.0, 3.16, 4_690.48),
+];
+
+const L1: [Term; 34] = [
+    (628_331_966_747.0, 0.0, 0.0),
+    (206_059.0, 2.678_235, 6_283.075_85),
+    (4_303.0, 2.635_1, 12_566.151_7),
+    (425.0, 1.59, 3.523),
+    (119.0, 5.796, 26.298),
+    (109.0, 2.966, 1_577.344),
+    (93.0, 2.59, 18_849.23),
+    (72.0, 1.14, 529.69),
+    (68.0, 1.87, 398.15),
+    (67.0, 4.41, 5_507.55),
+    (59.0, 2.89, 5_223.69),
+    (56.0, 2.17, 155.42),
+    (45.0, 0.4, 796.3),
+    (36.0, 0.47, 775.52),
+    (29.0, 2.65, 7.11),
+    (21.0, 5.34, 0.98),
+    (19.0, 1.85, 5_486.78),
+    (19.0, 4.97, 213.3),
+    (17.0, 2.99, 6_275.96),
+    (16.0, 0.03, 2_544.31),
+    (16.0, 1.43, 2_146.17),
+    (15.0, 1.21, 10_977.08),
+    (12.0, 2.83, 1_748.02),
+    (12.0, 3.26, 5_088.63),
+    (12.0, 5.27, 1_194.45),
+    (12.0, 2.08, 4_694.0),
+    (11.0, 0.77, 553.57),
+    (10.0, 1.3, 6_286.6),
+    (10.0, 4.24, 1_349.87),
+    (9.0, 2.7, 242.73),
+    (9.0, 5.64, 951.72),
+    (8.0, 5.3, 2_352.87),
+    (6.0, 2.65, 9_437.76),
+    (6.0, 4.67, 4_690.48),
+];
+
+const L2: [Term; 20] = [
+    (52_919.0, 0.0, 0.0),
+    (8_720.0, 1.072_1, 6_283.075_8),
+    (309.0, 0.867, 12_566.152),
+    (27.0, 0.05, 3.52),
+    (16.0, 5.19, 26.3),
+    (16.0, 3.68, 155.42),
+    (10.0, 0.76, 18_849.23),
+    (9.0, 2.06, 77_713.77),
+    (7.0, 0.83, 775.52),
+    (5.0, 4.66, 1_577.34),
+    (4.0, 1.03, 7.11),
+    (4.0, 3.44, 5_573.14),
+    (3.0, 5.14, 796.3),
+    (3.0, 6.05, 5_507.55),
+    (3.0, 1.19, 242.73),
+    (3.0, 6.12, 529.69),
+    (3.0, 0.31, 398.15),
+    (3.0, 2.28, 553.57),
+    (2.0, 4.38, 5_223.69),
+    (2.0, 3.75, 0.98),
+];
+
+const L3: [Term; 7] = [
+    (289.0, 5.844, 6_283.076),
+    (35.0, 0.0, 0.0),
+    (17.0, 5.49, 12_566.15),
+    (3.0, 5.2, 155.42),
+    (1.0, 4.72, 3.52),
+    (1.0, 5.3, 18_849.23),
+    (1.0, 5.97, 242.73),
+];
+
+const L4: [Term; 3] = [
+    (114.0, PI, 0.0),
+    (8.0, 4.13, 6_283.08),
+    (1.0, 3.84, 12_566.15),
+];
+
+const L5: [Term; 1] = [(1.0, PI, 0.0)];
+
+const R0: [Term; 10] = [
+    (100_013_989.0, 0.0, 0.0),
+    (1_670_700.0, 3.098_463_5, 6_283.075_85),
+    (13_956.0, 3.055_25, 12_566.151_7),
+    (3_084.0, 5.198_5, 77_713.771_5),
+    (1_628.0, 1.173_9, 5_753.384_9),
+    (1_576.0, 2.846_9, 7_860.419_4),
+    (925.0, 5.453, 11_506.77),
+    (542.0, 4.564, 3_930.21),
+    (472.0, 3.661, 5_884.927),
+    (346.0, 0.964, 5_507.553),
+];
+
+const R1: [Term; 2] = [
+    (103_019.0, 1.107_49, 6_283.075_85),
+    (1_721.0, 1.064_4, 12_566.151_7),
+];
+
+/// Evaluates the series `L0 + L1 τ + L2 τ² + …`, scaled from units of 10⁻⁸.
+fn series(series: &[&[Term]], tau: f64) -> f64 {
+    series.iter().rev().fold(0.0, |sum, terms| {
+        let value: f64 = terms.iter().map(|(a, b, c)| a * (b + c * tau).cos()).sum();
+
+        sum * tau + value
+    }) / 1e8
+}
+
+/// Returns the apparent geocentric longitude of the Sun at the Julian
+/// Ephemeris Day `jde`, in degrees referred to the true equinox of date.
+pub(crate) fn apparent_solar_longitude(jde: f64) -> f64 {
+    let tau = (jde - J2000) / DAYS_PER_MILLENNIUM;
+    let t = tau * 10.0;
+
+    let longitude = series(&[&L0, &L1, &L2, &L3, &L4, &L5], tau);
+    let radius = series(&[&R0, &R1], tau);
+
+    // geocentric longitude, converted to the FK5 system
+    let sun = longitude.to_degrees() + 180.0 - 0.090_33 / 3600.0;
+
+    // nutation in longitude, from the main terms of chapter 22
+    let node = (125.044_52 - 1_934.136_261 * t).to_radians();
+    let sun_mean = (280.466_5 + 36_000.769_8 * t).to_radians();
+    let moon_mean = (218.316_5 + 481_267.881_3 * t).to_radians();
+    let nutation =
+        -17.2 * node.sin() - 1.32 * (2.0 * sun_mean).sin() - 0.23 * (2.0 * moon_mean).sin()
+            + 0.21 * (2.0 * node).sin();
+
+    let aberration = -20.489_8 / radius;
+
+    (sun + (nutation + aberration) / 3600.0).rem_euclid(360.0)
+}
+
+/// Returns the Julian Ephemeris Day of the autumn equinox of the northern
+/// hemisphere in `gregorian_year`, when the apparent longitude of the Sun
+/// reaches 180°.
+fn autumn_equinox_tt(gregorian_year: i32) -> f64 {
+    let mut jde = 2_451_810.217_15 + 365.242_2 * (f64::from(gregorian_year) - 2000.0);
+
+    for _ in 0..50 {
+        let correction = 58.0 * (180.0 - apparent_solar_longitude(jde)).to_radians().sin();
+        jde += correction;
+
+        if correction.abs() < 1e-7 {
+            break;
+        }
+    }
+
+    jde
+}
+
+/// Returns the Julian Date in UT of the autumn equinox of `gregorian_year`,
+/// converted from TT with `model`.
+pub(crate) fn autumn_equinox_ut<M: DeltaT + ?Sized>(gregorian_year: i32, model: &M) -> f64 {
+    // the equinox falls about 72% of the way through the year
+    let delta_t = model.delta_t(f64::from(gregorian_year) + 0.72);
+
+    autumn_equinox_tt(gregorian_year) - delta_t / 86_400.0
+}
+
+/// Returns the Julian Day Number of the day in Paris mean time containing the
+/// autumn equinox of `gregorian_year`, converted from TT with `model`.
+pub(crate) fn equinox_day<M: DeltaT + ?Sized>(gregorian_year: i32, model: &M) -> i64 {
+    let paris =
+        autumn_equinox_ut(gregorian_year, model) + f64::from(PARIS_MEAN_TIME_OFFSET) / 86_400.0;
+
+    (paris + 0.5).floor() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delta_t::EspenakMeeus;
+
+    #[test]
+    fn solar_longitude() {
+        // Meeus, example 25.b: 199° 54′ 21.818″ on 1992 October 13.0 TD
+        let longitude = apparent_solar_longitude(2_448_908.5);
+        assert!((longitude - 199.906_061).abs() < 0.000_3, "{}", longitude);
+    }
+
+    #[test]
+    fn equinoxes() {
+        let equinoxes = [
+            // 9h 18m 30s true solar time at Paris, as recorded in the decree
+            // establishing the calendar, which is 9h 10m mean time
+            (1792, 2_375_839.876),
+            // 17h 27m UT
+            (2000, 2_451_810.227),
+            // 12h 44m UT
+            (2024, 2_460_576.031),
+        ];
+
+        for (year, expected) in equinoxes {
+            let ut = autumn_equinox_ut(year, &EspenakMeeus);
+            assert!((ut - expected).abs() < 0.002, "{}: {}", year, ut);
+        }
+    }
+}
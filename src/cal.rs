@@ -1,7 +1,9 @@
+use std::sync::OnceLock;
+
 pub const START_JD: i32 = -3099887;
 pub const START_YEAR: i32 = -14991;
 
-pub const LEAP_YEARS: [u8; 30390] = [
+pub static LEAP_YEARS: [u8; 30390] = [
     0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1,
     0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0,
     1, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0,
@@ -954,7 +956,7 @@ pub const LEAP_YEARS: [u8; 30390] = [
     0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0,
 ];
 
-pub const RURAL_DAYS: [(&'static str, &'static str); 360] = [
+pub const RURAL_DAYS: [(&str, &str); 360] = [
     ("Raisin", "Grape"),
     ("Safran", "Saffron"),
     ("Châtaigne", "Chestnut"),
@@ -1317,15 +1319,21 @@ pub const RURAL_DAYS: [(&'static str, &'static str); 360] = [
     ("Panier", "Pack Basket"),
 ];
 
-pub fn leaps_to_date() -> [u64; 30391] {
-    let mut leaps_to_date: u64 = 0;
+/// Cumulative count of leap years preceding each entry of [`LEAP_YEARS`],
+/// computed once on first use.
+pub fn leaps_to_date() -> &'static [u64; 30391] {
+    static LEAPS_TO_DATE: OnceLock<[u64; 30391]> = OnceLock::new();
+
+    LEAPS_TO_DATE.get_or_init(|| {
+        let mut leaps_to_date: u64 = 0;
 
-    let mut years = vec![0];
+        let mut years = vec![0];
 
-    for year in LEAP_YEARS {
-        leaps_to_date += year as u64;
-        years.push(leaps_to_date)
-    }
+        for year in LEAP_YEARS {
+            leaps_to_date += year as u64;
+            years.push(leaps_to_date)
+        }
 
-    years.as_slice().try_into().unwrap()
+        years.as_slice().try_into().unwrap()
+    })
 }
@@ -1,9 +1,12 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use thiserror::Error;
 
 use crate::{
     cal,
+    day_kind::DayKind,
+    decade::{self, Decade},
+    format::FormatDescription,
     month::Month,
     numeral,
     weekday::{Complimentary, Ordinary, Weekday},
@@ -19,6 +22,26 @@ pub enum DateError {
     #[error("Invalid Georgian calendar date provided")]
     InvalidGeorgianCalendarDate,
 
+    #[error("Date cannot be represented in the Gregorian calendar")]
+    GregorianOutOfRange,
+
+    #[error("Could not parse FRC date")]
+    ParseError,
+
+    #[error("Parameters passed create an invalid decimal time")]
+    InvalidTime,
+
+    #[error("Invalid format description")]
+    InvalidFormatDescription,
+
+    #[error("Invalid ΔT table")]
+    InvalidDeltaT,
+
+    /// The date falls outside of the range supported by the implementation,
+    /// given as the first and last supported Julian Day Numbers.
+    #[error("Date is outside of the supported range (Julian Day {min} to {max})")]
+    OutOfRange { min: i64, max: i64 },
+
     #[error("Cannot determine time zone")]
     IndeterminateTimezone(#[from] time::error::IndeterminateOffset),
 
@@ -26,68 +49,704 @@ pub enum DateError {
     ComponentRange(#[from] time::error::ComponentRange),
 }
 
-pub trait FrcDate {
+/// Common behaviour of every implementation of the FRC. Implementations
+/// provide the year, month and day along with a conversion to and from the
+/// Julian Day Number; everything else is derived from those.
+pub trait FrcDate: Sized {
+    /// Julian Day Number of the first date supported by the implementation.
+    const MIN_JULIAN_DAY: i64;
+
+    /// Julian Day Number of the last date supported by the implementation.
+    const MAX_JULIAN_DAY: i64;
+
     fn month_int(&self) -> u8;
     fn day(&self) -> u8;
     fn year(&self) -> i32;
-    fn is_leap_year(&self) -> bool;
+
+    /// Returns whether `year` is a leap year, i.e. has six Complémentaires
+    /// days, under the rule of this implementation.
+    fn leap_year(year: i32) -> bool;
+
+    fn is_leap_year(&self) -> bool {
+        Self::leap_year(self.year())
+    }
+
+    /// Returns the Julian Day Number of this date.
+    fn julian_day(&self) -> i64;
+
+    /// Constructs a date from a Julian Day Number, failing if the day falls
+    /// outside of the range supported by the implementation.
+    fn from_julian_day(jdn: i64) -> DateResult<Self>;
+
+    /// Constructs a date from its FRC year, month and day, where month 13 is
+    /// the Complémentaires.
+    fn from_frc(year: i32, month: u8, day: u8) -> DateResult<Self>;
+
+    /// Returns the date `days` days after this one. Negative values step
+    /// backwards.
+    fn checked_add_days(&self, days: i64) -> DateResult<Self> {
+        let jdn = self
+            .julian_day()
+            .checked_add(days)
+            .ok_or_else(out_of_range::<Self>)?;
+
+        Self::from_julian_day(jdn)
+    }
+
+    /// Returns the date `days` days before this one.
+    fn checked_sub_days(&self, days: i64) -> DateResult<Self> {
+        let jdn = self
+            .julian_day()
+            .checked_sub(days)
+            .ok_or_else(out_of_range::<Self>)?;
+
+        Self::from_julian_day(jdn)
+    }
+
+    /// Returns the following day.
+    fn succ(&self) -> DateResult<Self> {
+        self.checked_add_days(1)
+    }
+
+    /// Returns the preceding day.
+    fn pred(&self) -> DateResult<Self> {
+        self.checked_sub_days(1)
+    }
+
+    /// Returns whether this is a day of an ordinary month or one of the
+    /// Complémentaires.
+    fn kind(&self) -> DayKind {
+        DayKind::from_month_day(self.month_int(), self.day())
+            .expect("implementations only hold valid months and days")
+    }
+
+    /// Constructs a date in `year` from the kind of day it is.
+    fn from_kind(year: i32, kind: DayKind) -> DateResult<Self> {
+        let (month, day) = kind.month_day();
+
+        Self::from_frc(year, month, day)
+    }
 
     fn weekday(&self) -> Weekday {
-        if self.month_int() == 13 {
-            Weekday::Complimentary(Complimentary::day_of_week(self.day().into()))
-        } else {
-            let day_of_week = self.day() % 11;
-            Weekday::Ordinary(Ordinary::day_of_week(day_of_week.into()))
+        match self.kind() {
+            DayKind::Ordinary(day) => {
+                let day = Ordinary::try_from((day.day() - 1) % 10 + 1)
+                    .expect("the day of the décade is between 1 and 10");
+
+                Weekday::Ordinary(day)
+            }
+            DayKind::Complementary(day) => Weekday::Complimentary(day),
+        }
+    }
+
+    /// Returns the number of days in this date's month: 30, or 5 or 6 for the
+    /// Complémentaires.
+    fn days_in_month(&self) -> u8 {
+        match (self.month_int(), self.is_leap_year()) {
+            (13, true) => 6,
+            (13, false) => 5,
+            _ => 30,
         }
     }
 
+    /// Returns the date `months` months after this one, counting the
+    /// Complémentaires as a month. If the day does not exist in the target
+    /// month, `policy` decides the outcome.
+    fn add_months(&self, months: i32, policy: OverflowPolicy) -> DateResult<Self> {
+        let index =
+            i64::from(self.year()) * 13 + i64::from(self.month_int()) - 1 + i64::from(months);
+        let year = i32::try_from(index.div_euclid(13)).map_err(|_| out_of_range::<Self>())?;
+        let month = (index.rem_euclid(13) + 1) as u8;
+
+        with_policy(year, month, self.day(), policy)
+    }
+
+    /// Returns the date `years` years after this one. The only day that may
+    /// not exist in the target year is the sixth day of the Complémentaires,
+    /// in which case `policy` decides the outcome.
+    fn add_years(&self, years: i32, policy: OverflowPolicy) -> DateResult<Self> {
+        let year = self
+            .year()
+            .checked_add(years)
+            .ok_or_else(out_of_range::<Self>)?;
+
+        with_policy(year, self.month_int(), self.day(), policy)
+    }
+
     fn month(&self) -> Month {
-        Month::nth(self.month_int())
+        Month::try_from(self.month_int()).expect("implementations only hold valid months")
     }
 
     fn day_of_year(&self) -> u16 {
-        let month_int: u16 = self.month_int().into();
-        let days_of_months = (month_int - 1) * 30;
-        days_of_months + self.day() as u16
+        self.kind().day_of_year()
     }
 
+    /// Returns the French and English names of the plant, animal or tool the
+    /// day is dedicated to, or `None` for the Complémentaires.
     fn rural_day(&self) -> Option<(&str, &str)> {
-        cal::RURAL_DAYS.get(self.day_of_year() as usize).cloned()
+        match self.kind() {
+            DayKind::Ordinary(_) => Some(cal::RURAL_DAYS[self.day_of_year() as usize - 1]),
+            DayKind::Complementary(_) => None,
+        }
+    }
+
+    /// Returns the first date after this one falling on `weekday`. The
+    /// Complémentaires have no day of the décade and are skipped: the décade
+    /// starts over on 1 Vendémiaire, so the décadi following 30 Fructidor is
+    /// 10 Vendémiaire.
+    fn next_weekday(&self, weekday: Ordinary) -> DateResult<Self> {
+        let mut date = self.succ()?;
+
+        while date.weekday() != Weekday::Ordinary(weekday) {
+            date = date.succ()?;
+        }
+
+        Ok(date)
+    }
+
+    /// Returns the last date before this one falling on `weekday`, skipping
+    /// the Complémentaires as [`FrcDate::next_weekday`] does.
+    fn previous_weekday(&self, weekday: Ordinary) -> DateResult<Self> {
+        let mut date = self.pred()?;
+
+        while date.weekday() != Weekday::Ordinary(weekday) {
+            date = date.pred()?;
+        }
+
+        Ok(date)
+    }
+
+    /// Returns the `n`th (1–3) date falling on `weekday` in this date's
+    /// month. Every ordinary month has exactly three of each day of the
+    /// décade, while the Complémentaires have none, so this fails with
+    /// [`DateError::InvalidDate`] for them.
+    fn nth_weekday_of_month(&self, n: u8, weekday: Ordinary) -> DateResult<Self> {
+        match self.kind() {
+            DayKind::Ordinary(day) if (1..=3).contains(&n) => Self::from_frc(
+                self.year(),
+                day.month().value(),
+                (n - 1) * 10 + weekday as u8,
+            ),
+            _ => Err(DateError::InvalidDate),
+        }
+    }
+
+    /// Returns the last date falling on `weekday` in this date's month, i.e.
+    /// the third.
+    fn last_weekday_of_month(&self, weekday: Ordinary) -> DateResult<Self> {
+        self.nth_weekday_of_month(3, weekday)
+    }
+
+    /// Returns the décade containing this date, or `None` for the
+    /// Complémentaires.
+    fn decade(&self) -> Option<Decade<Self>> {
+        Decade::of(self)
+    }
+
+    /// Returns the décade of the year (1–36) containing this date, or `None`
+    /// for the Complémentaires.
+    fn decade_of_year(&self) -> Option<u8> {
+        self.decade().map(|decade| decade.decade_of_year())
+    }
+
+    /// Formats this date as a Republican week date, e.g. "233-D04-7" for the
+    /// seventh day of the fourth décade of An 233. The Complémentaires are
+    /// written as a separate period, e.g. "233-C-3".
+    fn to_decade_date(&self) -> String {
+        decade::to_decade_date(self)
     }
+
+    /// Parses a Republican week date as written by
+    /// [`FrcDate::to_decade_date`].
+    fn from_decade_date(s: &str) -> DateResult<Self> {
+        decade::from_decade_date(s)
+    }
+}
+
+/// Decides what happens when month or year arithmetic lands on a day that does
+/// not exist in the target month, e.g. the 6th of the Complémentaires in a
+/// regular year, or the 25th of a month landing in the Complémentaires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Use the last day of the target month.
+    Clamp,
+    /// Carry the excess days over into the following month, which for the
+    /// Complémentaires is Vendémiaire of the next year.
+    Overflow,
+    /// Fail with [`DateError::InvalidDate`].
+    Error,
 }
 
+fn with_policy<D: FrcDate>(year: i32, month: u8, day: u8, policy: OverflowPolicy) -> DateResult<D> {
+    let last = D::from_frc(year, month, 1)?.days_in_month();
+
+    if day <= last {
+        return D::from_frc(year, month, day);
+    }
+
+    match policy {
+        OverflowPolicy::Clamp => D::from_frc(year, month, last),
+        OverflowPolicy::Overflow => {
+            D::from_frc(year, month, last)?.checked_add_days((day - last).into())
+        }
+        OverflowPolicy::Error => Err(DateError::InvalidDate),
+    }
+}
+
+/// Returns the error for a date falling outside of the range supported by `D`.
+pub(crate) fn out_of_range<D: FrcDate>() -> DateError {
+    DateError::OutOfRange {
+        min: D::MIN_JULIAN_DAY,
+        max: D::MAX_JULIAN_DAY,
+    }
+}
+
+/// Julian Day Number of 1 Vendémiaire An I, 22 September 1792.
+pub(crate) const EPOCH_JDN: i64 = 2_375_840;
+
+/// Implements day arithmetic on an FRC date type through its [`FrcDate`]
+/// implementation: adding or subtracting an `i64` number of days or the whole
+/// days of a [`time::Duration`], and subtracting two dates to get the signed
+/// number of days between them.
+macro_rules! impl_day_arithmetic {
+    ($date:ident $(<$param:ident: $bound:path>)?) => {
+        impl$(<$param: $bound>)? std::ops::Add<time::Duration> for $date$(<$param>)? {
+            type Output = $crate::date::DateResult<Self>;
+
+            /// Adds the whole days of `duration` to this date.
+            fn add(self, duration: time::Duration) -> Self::Output {
+                $crate::date::FrcDate::checked_add_days(&self, duration.whole_days())
+            }
+        }
+
+        impl$(<$param: $bound>)? std::ops::Sub<time::Duration> for $date$(<$param>)? {
+            type Output = $crate::date::DateResult<Self>;
+
+            /// Subtracts the whole days of `duration` from this date.
+            fn sub(self, duration: time::Duration) -> Self::Output {
+                $crate::date::FrcDate::checked_sub_days(&self, duration.whole_days())
+            }
+        }
+
+        impl$(<$param: $bound>)? std::ops::Sub for $date$(<$param>)? {
+            type Output = i64;
+
+            /// Returns the signed number of days from `other` to this date.
+            fn sub(self, other: Self) -> Self::Output {
+                $crate::date::FrcDate::julian_day(&self) - $crate::date::FrcDate::julian_day(&other)
+            }
+        }
+
+        impl$(<$param: $bound>)? std::ops::Add<i64> for $date$(<$param>)? {
+            type Output = $crate::date::DateResult<Self>;
+
+            fn add(self, days: i64) -> Self::Output {
+                $crate::date::FrcDate::checked_add_days(&self, days)
+            }
+        }
+
+        impl$(<$param: $bound>)? std::ops::Sub<i64> for $date$(<$param>)? {
+            type Output = $crate::date::DateResult<Self>;
+
+            fn sub(self, days: i64) -> Self::Output {
+                $crate::date::FrcDate::checked_sub_days(&self, days)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_day_arithmetic;
+
+/// Returns whether `month` and `day` name an existing day in a year with the
+/// given leap status.
+pub(crate) fn is_valid_day(month: u8, day: u8, is_leap: bool) -> bool {
+    match month {
+        1..=12 => (1..=30).contains(&day),
+        13 => (1..=if is_leap { 6 } else { 5 }).contains(&day),
+        _ => false,
+    }
+}
+
+/// Wraps any [`FrcDate`] implementation and provides formatting for it. This is
+/// the entry point for displaying FRC dates, e.g. "1 Brumaire An CCXXXIII".
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Date<D: FrcDate> {
     date: D,
 }
 
+impl<D: FrcDate> From<D> for Date<D> {
+    fn from(date: D) -> Self {
+        Date::new(date)
+    }
+}
+
 impl<D: FrcDate> Display for Date<D> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let year = if self.date.year() < 0 || self.date.year() > (numeral::MAX_NUMERAL_VALUE as i32)
-        {
-            format!("{}", self.date.year())
-        } else {
-            format!("{}", numeral::to_numeral(self.date.year() as u16).unwrap())
+        write!(
+            f,
+            "{} {} An {}",
+            self.date.day(),
+            self.date.month(),
+            format_year(self.date.year())
+        )
+    }
+}
+
+impl<D: FrcDate> FromStr for Date<D> {
+    type Err = DateError;
+
+    /// Parses what `Display` prints, e.g. "1 Brumaire An CCXXXIII". The year
+    /// may also be given in Arabic numerals, the Complémentaires may be given
+    /// by the name of their fête, e.g. "La Fête du Génie An II", and ordinary
+    /// days may be preceded by the day of the décade, e.g. "Primidi 1 Brumaire
+    /// An CCXXXIII", which must agree with the date.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+
+        let (year, head) = match tokens.as_slice() {
+            [head @ .., an, year] if an.eq_ignore_ascii_case("an") => (parse_year(year)?, head),
+            _ => return Err(DateError::ParseError),
+        };
+
+        if let Ok(complimentary) = head.join(" ").parse::<Complimentary>() {
+            return D::from_kind(year, DayKind::Complementary(complimentary)).map(Date::new);
+        }
+
+        let (weekday, day, month) = match head {
+            [day, month] => (None, day, month),
+            [weekday, day, month] => (Some(weekday.parse::<Ordinary>()?), day, month),
+            _ => return Err(DateError::ParseError),
         };
 
-        write!(f, "{} {} An {}", self.date.day(), self.date.month(), year)
+        let day = day.parse::<u8>().map_err(|_| DateError::ParseError)?;
+        let month = month.parse::<Month>()?;
+        let date = D::from_frc(year, month.value(), day)?;
+
+        if let Some(weekday) = weekday {
+            if date.weekday() != Weekday::Ordinary(weekday) {
+                return Err(DateError::InvalidDate);
+            }
+        }
+
+        Ok(Date::new(date))
     }
 }
 
+fn parse_year(year: &str) -> DateResult<i32> {
+    if let Ok(year) = year.parse::<i32>() {
+        return Ok(year);
+    }
+
+    numeral::from_numeral(year)
+        .and_then(|year| i32::try_from(year).ok())
+        .ok_or(DateError::ParseError)
+}
+
 impl<D: FrcDate> Date<D> {
+    pub fn new(date: D) -> Self {
+        Self { date }
+    }
+
+    /// Returns a reference to the wrapped date.
+    pub fn inner(&self) -> &D {
+        &self.date
+    }
+
+    /// Consumes the wrapper, returning the wrapped date.
+    pub fn into_inner(self) -> D {
+        self.date
+    }
+
+    /// Formats the date according to `description`, e.g. `%A %d %B an %Y`.
+    /// See [`FormatDescription`] for the supported specifiers.
+    pub fn format(&self, description: &FormatDescription) -> String {
+        description.format(&self.date)
+    }
+
     pub fn year_as_numeral(&self) -> Option<String> {
-        let year = self.date.year();
+        year_as_numeral(self.date.year())
+    }
+}
+
+fn year_as_numeral(year: i32) -> Option<String> {
+    u32::try_from(year)
+        .ok()
+        .and_then(|year| numeral::to_extended_numeral(year, numeral::Case::Upper))
+}
 
-        if year < 0 {
-            None
-        } else {
-            let year = year.try_into();
+/// Writes `year` in Roman numerals, falling back to Arabic numerals for years
+/// that have none.
+pub(crate) fn format_year(year: i32) -> String {
+    year_as_numeral(year).unwrap_or_else(|| year.to_string())
+}
 
-            if year.is_err() {
-                None
-            } else {
-                numeral::to_numeral(year.unwrap())
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QDate, RommeDate};
+
+    #[test]
+    fn display() {
+        let dates = &[
+            ((233, 2, 1), "1 Brumaire An CCXXXIII"),
+            ((8, 2, 18), "18 Brumaire An VIII"),
+            ((3, 13, 6), "6 Complémentaires An III"),
+            ((15_001, 1, 1), "1 Vendémiaire An X\u{305}V\u{305}I"),
+            ((0, 1, 1), "1 Vendémiaire An 0"),
+        ];
+
+        for &((year, month, day), expected) in dates {
+            let date = Date::new(QDate::from_frc(year, month, day).unwrap());
+            assert_eq!(date.to_string(), expected);
+            assert_eq!(expected.parse::<Date<QDate>>().unwrap(), date);
         }
+
+        let date: Date<RommeDate> = RommeDate::from_frc(233, 2, 1).unwrap().into();
+        assert_eq!(date.to_string(), "1 Brumaire An CCXXXIII");
+        assert_eq!(date.year_as_numeral(), Some("CCXXXIII".to_string()));
+        assert_eq!(date.into_inner(), RommeDate::from_frc(233, 2, 1).unwrap());
+    }
+
+    #[test]
+    fn parse() {
+        let cases = &[
+            ("1 Brumaire An CCXXXIII", (233, 2, 1)),
+            ("1 Brumaire An 233", (233, 2, 1)),
+            ("18 brumaire an VIII", (8, 2, 18)),
+            ("Octidi 18 Brumaire An VIII", (8, 2, 18)),
+            ("  9   Thermidor  An  II ", (2, 11, 9)),
+            ("La Fête du Génie An II", (2, 13, 2)),
+            ("la fête de l'Opinion An 233", (233, 13, 4)),
+            ("6 Complémentaires An III", (3, 13, 6)),
+            ("1 Vendémiaire An -5", (-5, 1, 1)),
+        ];
+
+        for &(s, (year, month, day)) in cases {
+            let date: Date<QDate> = s.parse().unwrap();
+            assert_eq!(
+                date.into_inner(),
+                QDate::from_frc(year, month, day).unwrap(),
+                "{}",
+                s
+            );
+        }
+
+        let date: RommeDate = "Primidi 1 Brumaire An CCXXXIII".parse().unwrap();
+        assert_eq!(date, RommeDate::from_frc(233, 2, 1).unwrap());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        let cases = &[
+            "",
+            "1 Brumaire",
+            "1 Brumaire CCXXXIII",
+            "31 Brumaire An CCXXXIII",
+            "1 Brumair An CCXXXIII",
+            "1 Brumaire An IIII",
+            "Duodi 1 Brumaire An CCXXXIII",
+            "La Fête de la Révolution An CCXXXIII",
+            "Primidi Primidi 1 Brumaire An CCXXXIII",
+        ];
+
+        for s in cases {
+            assert!(s.parse::<Date<QDate>>().is_err(), "{} should not parse", s);
+        }
+    }
+
+    #[test]
+    fn parse_round_trip() {
+        let mut date = QDate::from_frc(1, 1, 1).unwrap();
+
+        while date.year() < 300 {
+            let parsed: QDate = date.to_string().parse().unwrap();
+            assert_eq!(parsed, date);
+
+            date = (date + 7).unwrap();
+        }
+    }
+
+    #[test]
+    fn month_arithmetic() {
+        let date = RommeDate::from_frc(233, 2, 18).unwrap();
+
+        let cases = &[
+            (1, (233, 3, 18)),
+            (-1, (233, 1, 18)),
+            (12, (234, 1, 18)),
+            (13, (234, 2, 18)),
+            (-14, (232, 1, 18)),
+            (0, (233, 2, 18)),
+        ];
+
+        for &(months, (year, month, day)) in cases {
+            assert_eq!(
+                date.add_months(months, OverflowPolicy::Error).unwrap(),
+                RommeDate::from_frc(year, month, day).unwrap(),
+                "adding {} months",
+                months
+            );
+        }
+    }
+
+    #[test]
+    fn month_overflow_policies() {
+        let date = RommeDate::from_frc(233, 12, 25).unwrap();
+
+        assert_eq!(
+            date.add_months(1, OverflowPolicy::Clamp).unwrap(),
+            RommeDate::from_frc(233, 13, 5).unwrap()
+        );
+        assert_eq!(
+            date.add_months(1, OverflowPolicy::Overflow).unwrap(),
+            RommeDate::from_frc(234, 1, 20).unwrap()
+        );
+        assert!(matches!(
+            date.add_months(1, OverflowPolicy::Error),
+            Err(DateError::InvalidDate)
+        ));
+
+        // An CCXXXII is a leap year under the Romme rule
+        let date = RommeDate::from_frc(231, 12, 25).unwrap();
+        assert_eq!(
+            date.add_months(14, OverflowPolicy::Clamp).unwrap(),
+            RommeDate::from_frc(232, 13, 6).unwrap()
+        );
+    }
+
+    #[test]
+    fn year_arithmetic() {
+        let date = RommeDate::from_frc(232, 13, 6).unwrap();
+
+        assert_eq!(
+            date.add_years(4, OverflowPolicy::Error).unwrap(),
+            RommeDate::from_frc(236, 13, 6).unwrap()
+        );
+        assert_eq!(
+            date.add_years(1, OverflowPolicy::Clamp).unwrap(),
+            RommeDate::from_frc(233, 13, 5).unwrap()
+        );
+        assert_eq!(
+            date.add_years(1, OverflowPolicy::Overflow).unwrap(),
+            RommeDate::from_frc(234, 1, 1).unwrap()
+        );
+        assert!(date.add_years(1, OverflowPolicy::Error).is_err());
+
+        let date = QDate::from_frc(233, 2, 1).unwrap();
+        assert_eq!(
+            date.add_years(-232, OverflowPolicy::Error).unwrap(),
+            QDate::from_frc(1, 2, 1).unwrap()
+        );
+        assert!(date.add_years(i32::MAX, OverflowPolicy::Error).is_err());
+    }
+
+    #[test]
+    fn weekdays() {
+        let dates = &[
+            (1, Weekday::Ordinary(Ordinary::Primidi)),
+            (9, Weekday::Ordinary(Ordinary::Nonidi)),
+            (10, Weekday::Ordinary(Ordinary::Décadi)),
+            (11, Weekday::Ordinary(Ordinary::Primidi)),
+            (20, Weekday::Ordinary(Ordinary::Décadi)),
+            (25, Weekday::Ordinary(Ordinary::Quintidi)),
+            (30, Weekday::Ordinary(Ordinary::Décadi)),
+        ];
+
+        for (day, expected) in dates {
+            let date = RommeDate::from_frc(233, 2, *day).unwrap();
+            assert_eq!(&date.weekday(), expected, "day {}", day);
+        }
+
+        let date = RommeDate::from_frc(233, 13, 2).unwrap();
+        assert_eq!(date.weekday(), Weekday::Complimentary(Complimentary::Génie));
+    }
+
+    #[test]
+    fn weekday_navigation() {
+        let date = QDate::from_frc(233, 7, 14).unwrap();
+
+        assert_eq!(
+            date.next_weekday(Ordinary::Décadi).unwrap(),
+            QDate::from_frc(233, 7, 20).unwrap()
+        );
+        assert_eq!(
+            date.next_weekday(Ordinary::Quartidi).unwrap(),
+            QDate::from_frc(233, 7, 24).unwrap()
+        );
+        assert_eq!(
+            date.previous_weekday(Ordinary::Quartidi).unwrap(),
+            QDate::from_frc(233, 7, 4).unwrap()
+        );
+        assert_eq!(
+            date.previous_weekday(Ordinary::Quintidi).unwrap(),
+            QDate::from_frc(233, 7, 5).unwrap()
+        );
+    }
+
+    #[test]
+    fn weekday_navigation_skips_complementaires() {
+        let date = QDate::from_frc(232, 12, 28).unwrap();
+
+        assert_eq!(
+            date.next_weekday(Ordinary::Décadi).unwrap(),
+            QDate::from_frc(232, 12, 30).unwrap()
+        );
+        assert_eq!(
+            date.next_weekday(Ordinary::Primidi).unwrap(),
+            QDate::from_frc(233, 1, 1).unwrap()
+        );
+
+        let complementary = QDate::from_frc(232, 13, 3).unwrap();
+        assert_eq!(
+            complementary.next_weekday(Ordinary::Décadi).unwrap(),
+            QDate::from_frc(233, 1, 10).unwrap()
+        );
+        assert_eq!(
+            complementary.previous_weekday(Ordinary::Primidi).unwrap(),
+            QDate::from_frc(232, 12, 21).unwrap()
+        );
+
+        let first = QDate::from_frc(233, 1, 2).unwrap();
+        assert_eq!(
+            first.previous_weekday(Ordinary::Décadi).unwrap(),
+            QDate::from_frc(232, 12, 30).unwrap()
+        );
+    }
+
+    #[test]
+    fn nth_weekday_of_month() {
+        let date = RommeDate::from_frc(233, 7, 14).unwrap();
+
+        assert_eq!(
+            date.nth_weekday_of_month(2, Ordinary::Nonidi).unwrap(),
+            RommeDate::from_frc(233, 7, 19).unwrap()
+        );
+        assert_eq!(
+            date.last_weekday_of_month(Ordinary::Quintidi).unwrap(),
+            RommeDate::from_frc(233, 7, 25).unwrap()
+        );
+        assert_eq!(
+            date.nth_weekday_of_month(1, Ordinary::Décadi).unwrap(),
+            RommeDate::from_frc(233, 7, 10).unwrap()
+        );
+        assert!(date.nth_weekday_of_month(0, Ordinary::Primidi).is_err());
+        assert!(date.nth_weekday_of_month(4, Ordinary::Primidi).is_err());
+
+        let complementary = RommeDate::from_frc(233, 13, 1).unwrap();
+        assert!(complementary
+            .nth_weekday_of_month(1, Ordinary::Primidi)
+            .is_err());
+    }
+
+    #[test]
+    fn rural_days() {
+        let first = RommeDate::from_frc(233, 1, 1).unwrap();
+        assert_eq!(first.rural_day(), Some(("Raisin", "Grape")));
+
+        let last = RommeDate::from_frc(233, 12, 30).unwrap();
+        assert_eq!(last.rural_day(), Some(("Panier", "Pack Basket")));
+
+        let complementary = RommeDate::from_frc(233, 13, 1).unwrap();
+        assert_eq!(complementary.rural_day(), None);
     }
 }
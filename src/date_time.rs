@@ -0,0 +1,283 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    ops::{Add, Sub},
+};
+
+use crate::{
+    date::{Date, DateError, DateResult, FrcDate},
+    decimal_time::DecimalTime,
+};
+
+/// Standard nanoseconds in a day
+const NANOS_PER_DAY: i128 = 86_400 * 1_000_000_000;
+
+/// A date and decimal time of day at a given offset from UTC, generic over the
+/// FRC implementation used for the date.
+///
+/// Equality and ordering compare the instants represented, to the nearest
+/// standard nanosecond, so the same moment expressed at two different offsets
+/// compares equal, as with `time::OffsetDateTime`.
+#[derive(Debug, Clone, Copy)]
+pub struct FrcDateTime<D: FrcDate> {
+    date: D,
+    time: DecimalTime,
+    offset: time::UtcOffset,
+}
+
+impl<D: FrcDate> PartialEq for FrcDateTime<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instant() == other.instant()
+    }
+}
+
+impl<D: FrcDate> Eq for FrcDateTime<D> {}
+
+impl<D: FrcDate> PartialOrd for FrcDateTime<D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<D: FrcDate> Ord for FrcDateTime<D> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.instant().cmp(&other.instant())
+    }
+}
+
+impl<D: FrcDate> Hash for FrcDateTime<D> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instant().hash(state)
+    }
+}
+
+impl<D: FrcDate + Copy> Display for FrcDateTime<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", Date::new(self.date), self.time, self.offset)
+    }
+}
+
+impl<D: FrcDate> TryFrom<time::OffsetDateTime> for FrcDateTime<D> {
+    type Error = DateError;
+
+    fn try_from(date_time: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        FrcDateTime::from_offset_date_time(date_time)
+    }
+}
+
+impl<D: FrcDate> Add<time::Duration> for FrcDateTime<D> {
+    type Output = DateResult<FrcDateTime<D>>;
+
+    fn add(self, duration: time::Duration) -> Self::Output {
+        self.checked_add(duration)
+    }
+}
+
+impl<D: FrcDate> Sub<time::Duration> for FrcDateTime<D> {
+    type Output = DateResult<FrcDateTime<D>>;
+
+    fn sub(self, duration: time::Duration) -> Self::Output {
+        self.checked_sub(duration)
+    }
+}
+
+impl<D: FrcDate> Sub for FrcDateTime<D> {
+    type Output = time::Duration;
+
+    /// Returns the elapsed time from `other` to this instant.
+    fn sub(self, other: FrcDateTime<D>) -> Self::Output {
+        let nanos = self.instant() - other.instant();
+
+        time::Duration::new(
+            (nanos / 1_000_000_000) as i64,
+            (nanos % 1_000_000_000) as i32,
+        )
+    }
+}
+
+impl<D: FrcDate> FrcDateTime<D> {
+    pub fn new(date: D, time: DecimalTime, offset: time::UtcOffset) -> Self {
+        Self { date, time, offset }
+    }
+
+    pub fn now_utc() -> DateResult<Self> {
+        FrcDateTime::from_offset_date_time(time::OffsetDateTime::now_utc())
+    }
+
+    pub fn now_local() -> DateResult<Self> {
+        FrcDateTime::from_offset_date_time(time::OffsetDateTime::now_local()?)
+    }
+
+    /// Constructs the UTC date and time of a Unix timestamp in seconds.
+    pub fn from_unix_timestamp(timestamp: i64) -> DateResult<Self> {
+        FrcDateTime::from_offset_date_time(time::OffsetDateTime::from_unix_timestamp(timestamp)?)
+    }
+
+    /// Constructs the UTC date and time of a Unix timestamp in nanoseconds.
+    pub fn from_unix_timestamp_nanos(timestamp: i128) -> DateResult<Self> {
+        FrcDateTime::from_offset_date_time(time::OffsetDateTime::from_unix_timestamp_nanos(
+            timestamp,
+        )?)
+    }
+
+    pub fn from_offset_date_time(date_time: time::OffsetDateTime) -> DateResult<Self> {
+        Ok(FrcDateTime {
+            date: D::from_julian_day(date_time.to_julian_day().into())?,
+            time: date_time.time().into(),
+            offset: date_time.offset(),
+        })
+    }
+
+    pub fn to_offset_date_time(&self) -> DateResult<time::OffsetDateTime> {
+        let jdn =
+            i32::try_from(self.date.julian_day()).map_err(|_| DateError::GregorianOutOfRange)?;
+
+        Ok(time::Date::from_julian_day(jdn)?
+            .with_time(self.time.into())
+            .assume_offset(self.offset))
+    }
+
+    pub fn date(&self) -> &D {
+        &self.date
+    }
+
+    pub fn time(&self) -> DecimalTime {
+        self.time
+    }
+
+    pub fn offset(&self) -> time::UtcOffset {
+        self.offset
+    }
+
+    /// Returns the same instant expressed at `offset`.
+    pub fn to_offset(self, offset: time::UtcOffset) -> DateResult<Self> {
+        let shift = time::Duration::seconds(
+            i64::from(offset.whole_seconds()) - i64::from(self.offset.whole_seconds()),
+        );
+
+        let shifted = self.checked_add(shift)?;
+
+        Ok(FrcDateTime { offset, ..shifted })
+    }
+
+    pub fn checked_add(self, duration: time::Duration) -> DateResult<Self> {
+        let (time, days) = self.time.overflowing_add(duration);
+
+        Ok(FrcDateTime {
+            date: self.date.checked_add_days(days)?,
+            time,
+            offset: self.offset,
+        })
+    }
+
+    pub fn checked_sub(self, duration: time::Duration) -> DateResult<Self> {
+        self.checked_add(-duration)
+    }
+
+    /// Returns the instant in UTC, in standard nanoseconds since the start of
+    /// Julian Day 0.
+    fn instant(&self) -> i128 {
+        let day = self.date.julian_day() as i128 * NANOS_PER_DAY
+            - self.offset.whole_seconds() as i128 * 1_000_000_000;
+
+        // round to the nearest standard nanosecond
+        day + (self.time.nanos_since_midnight() as i128 * 216 + 125) / 250
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QDate, RommeDate};
+
+    #[test]
+    fn unix_timestamps() {
+        let epoch = FrcDateTime::<QDate>::from_unix_timestamp(0).unwrap();
+
+        assert_eq!(
+            *epoch.date(),
+            QDate::from_gregorian_date(1970, 1, 1).unwrap()
+        );
+        assert_eq!(epoch.time(), DecimalTime::MIDNIGHT);
+        assert_eq!(epoch.offset(), time::UtcOffset::UTC);
+
+        let noon = FrcDateTime::<RommeDate>::from_unix_timestamp(43_200).unwrap();
+        assert_eq!(noon.time(), DecimalTime::from_hms(5, 0, 0).unwrap());
+        assert_eq!(
+            noon - FrcDateTime::<RommeDate>::from_unix_timestamp(0).unwrap(),
+            time::Duration::hours(12)
+        );
+    }
+
+    #[test]
+    fn offset_date_time_round_trip() {
+        let date_time = time::Date::from_calendar_date(1799, time::Month::November, 9)
+            .unwrap()
+            .with_hms_nano(13, 41, 32, 123_456_789)
+            .unwrap()
+            .assume_offset(time::UtcOffset::from_hms(1, 0, 0).unwrap());
+        let frc = FrcDateTime::<QDate>::try_from(date_time).unwrap();
+
+        assert_eq!(*frc.date(), QDate::from_frc(8, 2, 18).unwrap());
+        assert_eq!(frc.time(), DecimalTime::from(date_time.time()));
+        assert_eq!(frc.to_offset_date_time().unwrap(), date_time);
+    }
+
+    #[test]
+    fn ordering_across_offsets() {
+        let utc = FrcDateTime::new(
+            QDate::from_frc(233, 1, 1).unwrap(),
+            DecimalTime::from_hms(0, 20, 0).unwrap(),
+            time::UtcOffset::UTC,
+        );
+        let paris = utc
+            .to_offset(time::UtcOffset::from_hms(1, 0, 0).unwrap())
+            .unwrap();
+
+        assert_eq!(paris, utc);
+        assert_eq!(paris.time(), utc.time() + time::Duration::hours(1));
+        assert!(utc < (paris + time::Duration::nanoseconds(1)).unwrap());
+
+        let new_york = utc
+            .to_offset(time::UtcOffset::from_hms(-5, 0, 0).unwrap())
+            .unwrap();
+        assert_eq!(*new_york.date(), QDate::from_frc(232, 13, 5).unwrap());
+        assert_eq!(new_york, utc);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let date_time = FrcDateTime::new(
+            RommeDate::from_frc(224, 13, 6).unwrap(),
+            DecimalTime::from_hms(9, 50, 0).unwrap(),
+            time::UtcOffset::UTC,
+        );
+
+        let next = (date_time + time::Duration::hours(12)).unwrap();
+        assert_eq!(*next.date(), RommeDate::from_frc(225, 1, 1).unwrap());
+        assert_eq!(next.time(), DecimalTime::from_hms(4, 50, 0).unwrap());
+        assert_eq!(next - date_time, time::Duration::hours(12));
+        assert_eq!((next - time::Duration::hours(12)).unwrap(), date_time);
+
+        let start = FrcDateTime::new(QDate::MIN, DecimalTime::MIDNIGHT, time::UtcOffset::UTC);
+        assert!(matches!(
+            start - time::Duration::seconds(1),
+            Err(DateError::OutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn display() {
+        let date_time = FrcDateTime::new(
+            QDate::from_frc(8, 2, 18).unwrap(),
+            DecimalTime::from_hms(5, 42, 18).unwrap(),
+            time::UtcOffset::from_hms(1, 0, 0).unwrap(),
+        );
+
+        assert_eq!(
+            date_time.to_string(),
+            "18 Brumaire An VIII 5h 42m 18s +01:00:00"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt::Display;
+
+use crate::{
+    date::{DateError, DateResult},
+    month::Month,
+    weekday::Complimentary,
+};
+
+/// The position of a day within its year, distinguishing the days of the
+/// twelve ordinary months from the five or six Complémentaires days, which
+/// belong to no month and no décade. Matching on it rather than on a month
+/// number of 13 makes it impossible to forget the Complémentaires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayKind {
+    /// A day of one of the twelve ordinary months.
+    Ordinary(OrdinaryDay),
+    /// One of the Complémentaires days, named after its fête.
+    Complementary(Complimentary),
+}
+
+/// A day of one of the twelve ordinary months. It can only be constructed
+/// through [`OrdinaryDay::new`], so the month is never
+/// [`Month::Complémentaires`] and the day always ranges from 1 to 30.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OrdinaryDay {
+    month: Month,
+    day: u8,
+}
+
+impl Display for DayKind {
+    /// Writes the day without its year, e.g. "18 Brumaire" or "La Fête du
+    /// Génie".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayKind::Ordinary(day) => day.fmt(f),
+            DayKind::Complementary(day) => write!(f, "{}", day),
+        }
+    }
+}
+
+impl Display for OrdinaryDay {
+    /// Writes the day without its year, e.g. "18 Brumaire".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.day, self.month)
+    }
+}
+
+impl OrdinaryDay {
+    /// Constructs the `day`th day of `month`, failing with
+    /// [`DateError::InvalidDate`] for the Complémentaires or a day outside of
+    /// 1 to 30.
+    pub fn new(month: Month, day: u8) -> DateResult<Self> {
+        if month == Month::Complémentaires || !(1..=30).contains(&day) {
+            return Err(DateError::InvalidDate);
+        }
+
+        Ok(OrdinaryDay { month, day })
+    }
+
+    /// Returns the month, never [`Month::Complémentaires`].
+    pub fn month(&self) -> Month {
+        self.month
+    }
+
+    /// Returns the day of the month, from 1 to 30.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl DayKind {
+    /// Constructs the kind of the day with the given month number (13 being
+    /// the Complémentaires) and day of the month.
+    pub fn from_month_day(month: u8, day: u8) -> DateResult<Self> {
+        match (month, day) {
+            (1..=12, _) => Ok(DayKind::Ordinary(OrdinaryDay::new(
+                Month::try_from(month)?,
+                day,
+            )?)),
+            (13, 1..=6) => Ok(DayKind::Complementary(Complimentary::try_from(day)?)),
+            _ => Err(DateError::InvalidDate),
+        }
+    }
+
+    /// Returns the month number, 13 for the Complémentaires, and the day of
+    /// the month.
+    pub fn month_day(&self) -> (u8, u8) {
+        match self {
+            DayKind::Ordinary(day) => (day.month.value(), day.day),
+            DayKind::Complementary(day) => (13, *day as u8),
+        }
+    }
+
+    /// Returns the day of the year, from 1 to 366.
+    pub fn day_of_year(&self) -> u16 {
+        let (month, day) = self.month_day();
+
+        (u16::from(month) - 1) * 30 + u16::from(day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{date::FrcDate, QDate, RommeDate};
+
+    #[test]
+    fn kinds() {
+        let date = QDate::from_frc(8, 2, 18).unwrap();
+        assert_eq!(
+            date.kind(),
+            DayKind::Ordinary(OrdinaryDay::new(Month::Brumaire, 18).unwrap())
+        );
+        assert_eq!(date.kind().to_string(), "18 Brumaire");
+
+        let date = QDate::from_frc(3, 13, 6).unwrap();
+        assert_eq!(
+            date.kind(),
+            DayKind::Complementary(Complimentary::Révolution)
+        );
+        assert_eq!(date.kind().to_string(), "La Fête de la Révolution");
+        assert_eq!(date.kind().day_of_year(), 366);
+    }
+
+    #[test]
+    fn from_kind() {
+        let kind = DayKind::Complementary(Complimentary::Génie);
+        assert_eq!(
+            RommeDate::from_kind(2, kind).unwrap(),
+            RommeDate::from_frc(2, 13, 2).unwrap()
+        );
+
+        let revolution = DayKind::Complementary(Complimentary::Révolution);
+        assert!(RommeDate::from_kind(3, revolution).is_err());
+        assert!(RommeDate::from_kind(4, revolution).is_ok());
+
+        let kind = DayKind::Ordinary(OrdinaryDay::new(Month::Thermidor, 9).unwrap());
+        assert_eq!(
+            RommeDate::from_kind(2, kind).unwrap(),
+            RommeDate::from_frc(2, 11, 9).unwrap()
+        );
+    }
+
+    #[test]
+    fn ordinary_days() {
+        let day = OrdinaryDay::new(Month::Thermidor, 9).unwrap();
+        assert_eq!((day.month(), day.day()), (Month::Thermidor, 9));
+        assert_eq!(day.to_string(), "9 Thermidor");
+
+        assert!(matches!(
+            OrdinaryDay::new(Month::Complémentaires, 1),
+            Err(DateError::InvalidDate)
+        ));
+        assert!(matches!(
+            OrdinaryDay::new(Month::Brumaire, 31),
+            Err(DateError::InvalidDate)
+        ));
+        assert!(matches!(
+            OrdinaryDay::new(Month::Brumaire, 0),
+            Err(DateError::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn month_day_round_trip() {
+        for month in 1..=13 {
+            for day in 1..=30 {
+                if let Ok(kind) = DayKind::from_month_day(month, day) {
+                    assert_eq!(kind.month_day(), (month, day));
+                }
+            }
+        }
+
+        assert!(DayKind::from_month_day(13, 7).is_err());
+        assert!(DayKind::from_month_day(0, 1).is_err());
+        assert!(DayKind::from_month_day(12, 0).is_err());
+    }
+}
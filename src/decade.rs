@@ -0,0 +1,290 @@
+use std::{fmt::Display, marker::PhantomData};
+
+use crate::{
+    date::{self, DateError, DateResult, FrcDate},
+    day_kind::DayKind,
+    month::Month,
+};
+
+/// A décade, the ten-day week of the FRC. Each of the twelve ordinary months
+/// holds three décades, giving 36 in a year. The Complémentaires are not part
+/// of any décade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Decade<D: FrcDate> {
+    year: i32,
+    month: Month,
+    number: u8,
+    date: PhantomData<D>,
+}
+
+impl<D: FrcDate> Display for Decade<D> {
+    /// Writes the décade as in a Republican week date, e.g. "233-D04".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-D{:02}", self.year, self.decade_of_year())
+    }
+}
+
+impl<D: FrcDate> Decade<D> {
+    /// Constructs the `number`th (1–3) décade of `month` in `year`.
+    pub fn new(year: i32, month: Month, number: u8) -> DateResult<Self> {
+        if month == Month::Complémentaires || !(1..=3).contains(&number) {
+            return Err(DateError::InvalidDate);
+        }
+
+        let decade = Decade {
+            year,
+            month,
+            number,
+            date: PhantomData,
+        };
+
+        // make sure every day of the décade is supported by the implementation
+        D::from_frc(year, month.value(), (number - 1) * 10 + 1)?;
+        D::from_frc(year, month.value(), number * 10)?;
+
+        Ok(decade)
+    }
+
+    /// Constructs the `decade`th (1–36) décade of `year`.
+    pub fn from_decade_of_year(year: i32, decade: u8) -> DateResult<Self> {
+        if !(1..=36).contains(&decade) {
+            return Err(DateError::InvalidDate);
+        }
+
+        Decade::new(
+            year,
+            Month::try_from((decade - 1) / 3 + 1)?,
+            (decade - 1) % 3 + 1,
+        )
+    }
+
+    /// Returns the décade containing `date`, or `None` for the Complémentaires
+    /// and for a décade the implementation only supports part of.
+    pub fn of(date: &D) -> Option<Self> {
+        match date.kind() {
+            DayKind::Ordinary(day) => {
+                Decade::new(date.year(), day.month(), (day.day() - 1) / 10 + 1).ok()
+            }
+            DayKind::Complementary(_) => None,
+        }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> Month {
+        self.month
+    }
+
+    /// Returns the position of the décade within its month, from 1 to 3.
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    /// Returns the position of the décade within its year, from 1 to 36.
+    pub fn decade_of_year(&self) -> u8 {
+        (self.month.value() - 1) * 3 + self.number
+    }
+
+    /// Returns the following décade, moving on to the first décade of the
+    /// next year after the third décade of Fructidor.
+    pub fn next(&self) -> DateResult<Self> {
+        match self.decade_of_year() {
+            36 => {
+                let year = self
+                    .year
+                    .checked_add(1)
+                    .ok_or_else(date::out_of_range::<D>)?;
+                Decade::from_decade_of_year(year, 1)
+            }
+            decade => Decade::from_decade_of_year(self.year, decade + 1),
+        }
+    }
+
+    /// Returns the preceding décade, moving back to the last décade of the
+    /// previous year before the first décade of Vendémiaire.
+    pub fn prev(&self) -> DateResult<Self> {
+        match self.decade_of_year() {
+            1 => {
+                let year = self
+                    .year
+                    .checked_sub(1)
+                    .ok_or_else(date::out_of_range::<D>)?;
+                Decade::from_decade_of_year(year, 36)
+            }
+            decade => Decade::from_decade_of_year(self.year, decade - 1),
+        }
+    }
+
+    pub fn first_day(&self) -> D {
+        self.nth_day(1)
+    }
+
+    pub fn last_day(&self) -> D {
+        self.nth_day(10)
+    }
+
+    /// Iterates over the ten days of the décade, from primidi to décadi.
+    pub fn days(&self) -> impl Iterator<Item = D> + '_ {
+        (1..=10).map(|n| self.nth_day(n))
+    }
+
+    fn nth_day(&self, n: u8) -> D {
+        D::from_frc(self.year, self.month.value(), (self.number - 1) * 10 + n)
+            .expect("every day of a décade exists once its year is supported")
+    }
+}
+
+/// Formats `date` as a Republican week date, the analogue of an ISO week
+/// date: the year, the décade of the year and the day of the décade, e.g.
+/// "233-D04-7". Complémentaires days form their own period, written with a
+/// "C" in place of the décade, e.g. "233-C-3".
+pub(crate) fn to_decade_date<D: FrcDate>(date: &D) -> String {
+    match date.kind() {
+        DayKind::Ordinary(day) => format!(
+            "{}-D{:02}-{}",
+            date.year(),
+            (day.month().value() - 1) * 3 + (day.day() - 1) / 10 + 1,
+            (day.day() - 1) % 10 + 1
+        ),
+        DayKind::Complementary(_) => format!("{}-C-{}", date.year(), date.day()),
+    }
+}
+
+/// Parses a Republican week date as written by [`to_decade_date`].
+pub(crate) fn from_decade_date<D: FrcDate>(s: &str) -> DateResult<D> {
+    let mut parts = s.rsplitn(3, '-');
+
+    let (Some(day), Some(period), Some(year)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(DateError::ParseError);
+    };
+
+    let year = year.parse::<i32>().map_err(|_| DateError::ParseError)?;
+    let day = day.parse::<u8>().map_err(|_| DateError::ParseError)?;
+
+    if period == "C" {
+        return D::from_frc(year, 13, day);
+    }
+
+    let decade = period
+        .strip_prefix('D')
+        .filter(|decade| decade.len() == 2)
+        .and_then(|decade| decade.parse::<u8>().ok())
+        .ok_or(DateError::ParseError)?;
+
+    if !(1..=10).contains(&day) {
+        return Err(DateError::InvalidDate);
+    }
+
+    let decade = Decade::<D>::from_decade_of_year(year, decade)?;
+
+    Ok(decade.nth_day(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HistoricalDate, QDate, RommeDate};
+
+    #[test]
+    fn decade_of_date() {
+        let date = QDate::from_frc(233, 2, 17).unwrap();
+        let decade = date.decade().unwrap();
+
+        assert_eq!(decade.year(), 233);
+        assert_eq!(decade.month(), Month::Brumaire);
+        assert_eq!(decade.number(), 2);
+        assert_eq!(decade.decade_of_year(), 5);
+        assert_eq!(date.decade_of_year(), Some(5));
+        assert_eq!(decade.first_day(), QDate::from_frc(233, 2, 11).unwrap());
+        assert_eq!(decade.last_day(), QDate::from_frc(233, 2, 20).unwrap());
+        assert_eq!(decade.to_string(), "233-D05");
+
+        let complementary = QDate::from_frc(233, 13, 1).unwrap();
+        assert!(complementary.decade().is_none());
+        assert_eq!(complementary.decade_of_year(), None);
+    }
+
+    #[test]
+    fn days() {
+        let decade = Decade::<RommeDate>::from_decade_of_year(233, 36).unwrap();
+        let days: Vec<RommeDate> = decade.days().collect();
+
+        assert_eq!(days.len(), 10);
+        assert_eq!(days[0], RommeDate::from_frc(233, 12, 21).unwrap());
+        assert_eq!(days[9], RommeDate::from_frc(233, 12, 30).unwrap());
+        assert!(days.windows(2).all(|w| w[0].succ().unwrap() == w[1]));
+    }
+
+    #[test]
+    fn navigation() {
+        let last = Decade::<QDate>::from_decade_of_year(232, 36).unwrap();
+        let first = last.next().unwrap();
+
+        assert_eq!(first, Decade::new(233, Month::Vendémiaire, 1).unwrap());
+        assert_eq!(first.prev().unwrap(), last);
+        assert_eq!(
+            first.next().unwrap(),
+            Decade::new(233, Month::Vendémiaire, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_decades() {
+        assert!(Decade::<QDate>::new(233, Month::Complémentaires, 1).is_err());
+        assert!(Decade::<QDate>::new(233, Month::Brumaire, 0).is_err());
+        assert!(Decade::<QDate>::new(233, Month::Brumaire, 4).is_err());
+        assert!(Decade::<QDate>::from_decade_of_year(233, 0).is_err());
+        assert!(Decade::<QDate>::from_decade_of_year(233, 37).is_err());
+        assert!(Decade::<QDate>::from_decade_of_year(20_000, 1).is_err());
+    }
+
+    #[test]
+    fn decade_dates() {
+        let cases = &[
+            ((233, 2, 7), "233-D04-7"),
+            ((233, 1, 10), "233-D01-10"),
+            ((233, 12, 30), "233-D36-10"),
+            ((3, 13, 6), "3-C-6"),
+            ((-5, 1, 1), "-5-D01-1"),
+        ];
+
+        for &((year, month, day), expected) in cases {
+            let date = QDate::from_frc(year, month, day).unwrap();
+
+            assert_eq!(date.to_decade_date(), expected);
+            assert_eq!(QDate::from_decade_date(expected).unwrap(), date);
+        }
+
+        for invalid in &[
+            "233-D4-7",
+            "233-D37-1",
+            "233-D04-11",
+            "233-C-6",
+            "233-X-1",
+            "233",
+        ] {
+            assert!(
+                QDate::from_decade_date(invalid).is_err(),
+                "{} should not parse",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn partly_supported_decade() {
+        // the historical calendar ends on 10 Nivôse An XIV, the last day of
+        // the first décade of the month
+        let last = Decade::<HistoricalDate>::new(14, Month::Nivôse, 1).unwrap();
+        assert_eq!(last.last_day(), HistoricalDate::MAX);
+        assert_eq!(HistoricalDate::MAX.decade(), Some(last));
+        assert!(matches!(last.next(), Err(DateError::OutOfRange { .. })));
+
+        assert!(matches!(
+            Decade::<HistoricalDate>::new(14, Month::Nivôse, 2),
+            Err(DateError::OutOfRange { .. })
+        ));
+    }
+}
@@ -0,0 +1,270 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Sub},
+};
+
+use crate::date::{DateError, DateResult};
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+const NANOS_PER_MINUTE: u64 = 100 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: u64 = 100 * NANOS_PER_MINUTE;
+const NANOS_PER_DAY: u64 = 10 * NANOS_PER_HOUR;
+
+/// A time of day in decimal time, as decreed alongside the FRC: the day is
+/// divided into 10 hours of 100 minutes of 100 seconds, so a decimal second
+/// lasts 0.864 standard seconds.
+///
+/// Sub-second precision is kept in decimal nanoseconds. Conversions from
+/// `time::Time` round down, and conversions back round up, so that a
+/// `time::Time` converted to decimal time and back is unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DecimalTime {
+    /// decimal nanoseconds since midnight
+    nanos: u64,
+}
+
+impl Display for DecimalTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}h {:02}m {:02}s",
+            self.hour(),
+            self.minute(),
+            self.second()
+        )
+    }
+}
+
+impl From<time::Time> for DecimalTime {
+    fn from(time: time::Time) -> Self {
+        let (hour, minute, second, nano) = time.as_hms_nano();
+        let standard_nanos =
+            ((hour as u64 * 60 + minute as u64) * 60 + second as u64) * 1_000_000_000 + nano as u64;
+
+        DecimalTime {
+            nanos: (standard_nanos as u128 * 125 / 108) as u64,
+        }
+    }
+}
+
+impl From<time::OffsetDateTime> for DecimalTime {
+    fn from(date_time: time::OffsetDateTime) -> Self {
+        date_time.time().into()
+    }
+}
+
+impl From<DecimalTime> for time::Time {
+    fn from(time: DecimalTime) -> Self {
+        // the last decimal nanosecond of the day would otherwise round up to midnight
+        let standard_nanos =
+            ((time.nanos as u128 * 108).div_ceil(125) as u64).min(86_400 * 1_000_000_000 - 1);
+        let seconds = standard_nanos / 1_000_000_000;
+
+        time::Time::from_hms_nano(
+            (seconds / 3600) as u8,
+            (seconds / 60 % 60) as u8,
+            (seconds % 60) as u8,
+            (standard_nanos % 1_000_000_000) as u32,
+        )
+        .expect("a decimal time always falls within a standard day")
+    }
+}
+
+impl Add<time::Duration> for DecimalTime {
+    type Output = DecimalTime;
+
+    /// Adds `duration` to this time, wrapping around midnight.
+    fn add(self, duration: time::Duration) -> Self::Output {
+        self.overflowing_add(duration).0
+    }
+}
+
+impl Sub<time::Duration> for DecimalTime {
+    type Output = DecimalTime;
+
+    /// Subtracts `duration` from this time, wrapping around midnight.
+    fn sub(self, duration: time::Duration) -> Self::Output {
+        self.overflowing_add(-duration).0
+    }
+}
+
+impl Sub for DecimalTime {
+    type Output = time::Duration;
+
+    /// Returns the standard duration from `other` to this time.
+    fn sub(self, other: DecimalTime) -> Self::Output {
+        let nanos = self.nanos as i64 - other.nanos as i64;
+
+        time::Duration::nanoseconds(nanos * 108 / 125)
+    }
+}
+
+impl DecimalTime {
+    pub const MIDNIGHT: DecimalTime = DecimalTime { nanos: 0 };
+
+    /// Constructs a time from decimal hours (0–9), minutes (0–99) and seconds
+    /// (0–99).
+    pub fn from_hms(hour: u8, minute: u8, second: u8) -> DateResult<Self> {
+        DecimalTime::from_hms_nano(hour, minute, second, 0)
+    }
+
+    /// Constructs a time from decimal hours, minutes, seconds and nanoseconds
+    /// of a decimal second.
+    pub fn from_hms_nano(hour: u8, minute: u8, second: u8, nanosecond: u32) -> DateResult<Self> {
+        if hour >= 10 || minute >= 100 || second >= 100 || nanosecond as u64 >= NANOS_PER_SECOND {
+            return Err(DateError::InvalidTime);
+        }
+
+        Ok(DecimalTime {
+            nanos: hour as u64 * NANOS_PER_HOUR
+                + minute as u64 * NANOS_PER_MINUTE
+                + second as u64 * NANOS_PER_SECOND
+                + nanosecond as u64,
+        })
+    }
+
+    pub fn hour(&self) -> u8 {
+        (self.nanos / NANOS_PER_HOUR) as u8
+    }
+
+    pub fn minute(&self) -> u8 {
+        (self.nanos % NANOS_PER_HOUR / NANOS_PER_MINUTE) as u8
+    }
+
+    pub fn second(&self) -> u8 {
+        (self.nanos % NANOS_PER_MINUTE / NANOS_PER_SECOND) as u8
+    }
+
+    /// Returns the nanoseconds within the current decimal second.
+    pub fn nanosecond(&self) -> u32 {
+        (self.nanos % NANOS_PER_SECOND) as u32
+    }
+
+    /// Returns the elapsed fraction of the day, from 0 up to but excluding 1.
+    pub fn fraction_of_day(&self) -> f64 {
+        self.nanos as f64 / NANOS_PER_DAY as f64
+    }
+
+    pub(crate) fn nanos_since_midnight(&self) -> u64 {
+        self.nanos
+    }
+
+    /// Adds `duration` to this time, returning the wrapped time along with
+    /// the number of days crossed, which is negative when stepping backwards
+    /// past midnight.
+    pub fn overflowing_add(self, duration: time::Duration) -> (DecimalTime, i64) {
+        let day = NANOS_PER_DAY as i128;
+        let standard = duration.whole_nanoseconds();
+        // round to the nearest decimal nanosecond
+        let decimal = (standard * 250 + standard.signum() * 108) / 216;
+        let total = self.nanos as i128 + decimal;
+
+        (
+            DecimalTime {
+                nanos: total.rem_euclid(day) as u64,
+            },
+            total.div_euclid(day) as i64,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_time() {
+        let cases = &[
+            ((0, 0, 0), (0, 0, 0)),
+            ((12, 0, 0), (5, 0, 0)),
+            ((6, 0, 0), (2, 50, 0)),
+            ((13, 41, 32), (5, 70, 50)),
+            ((23, 59, 59), (9, 99, 98)),
+        ];
+
+        for &((hour, minute, second), expected) in cases {
+            let time = time::Time::from_hms(hour, minute, second).unwrap();
+            let decimal = DecimalTime::from(time);
+
+            assert_eq!(
+                (decimal.hour(), decimal.minute(), decimal.second()),
+                expected,
+                "{}",
+                time
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for seconds in (0..86_400).step_by(7) {
+            for nano in [0, 1, 123_456_789, 999_999_999] {
+                let time = time::Time::from_hms_nano(
+                    (seconds / 3600) as u8,
+                    (seconds / 60 % 60) as u8,
+                    (seconds % 60) as u8,
+                    nano,
+                )
+                .unwrap();
+
+                assert_eq!(time::Time::from(DecimalTime::from(time)), time);
+            }
+        }
+
+        let last = DecimalTime::from_hms_nano(9, 99, 99, 999_999_999).unwrap();
+        assert_eq!(
+            time::Time::from(last),
+            time::Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap()
+        );
+    }
+
+    #[test]
+    fn components() {
+        let time = DecimalTime::from_hms_nano(5, 42, 18, 500).unwrap();
+
+        assert_eq!(time.hour(), 5);
+        assert_eq!(time.minute(), 42);
+        assert_eq!(time.second(), 18);
+        assert_eq!(time.nanosecond(), 500);
+        assert_eq!(time.to_string(), "5h 42m 18s");
+        assert_eq!(
+            DecimalTime::from_hms(5, 2, 3).unwrap().to_string(),
+            "5h 02m 03s"
+        );
+
+        assert!(DecimalTime::from_hms(10, 0, 0).is_err());
+        assert!(DecimalTime::from_hms(0, 100, 0).is_err());
+        assert!(DecimalTime::from_hms(0, 0, 100).is_err());
+        assert!(DecimalTime::from_hms_nano(0, 0, 0, 1_000_000_000).is_err());
+    }
+
+    #[test]
+    fn arithmetic() {
+        let time = DecimalTime::from_hms(9, 50, 0).unwrap();
+
+        assert_eq!(
+            DecimalTime::MIDNIGHT + time::Duration::hours(12),
+            DecimalTime::from_hms(5, 0, 0).unwrap()
+        );
+        assert_eq!(
+            time - time::Duration::hours(12),
+            DecimalTime::from_hms(4, 50, 0).unwrap()
+        );
+        assert_eq!(
+            time.overflowing_add(time::Duration::hours(12)),
+            (DecimalTime::from_hms(4, 50, 0).unwrap(), 1)
+        );
+        assert_eq!(
+            DecimalTime::MIDNIGHT.overflowing_add(time::Duration::seconds(-864)),
+            (DecimalTime::from_hms(9, 90, 0).unwrap(), -1)
+        );
+        assert_eq!(
+            DecimalTime::from_hms(5, 0, 0).unwrap() - DecimalTime::MIDNIGHT,
+            time::Duration::hours(12)
+        );
+        assert_eq!(
+            DecimalTime::MIDNIGHT - DecimalTime::from_hms(0, 1, 0).unwrap(),
+            time::Duration::seconds(-86) - time::Duration::milliseconds(400)
+        );
+    }
+}
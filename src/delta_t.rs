@@ -0,0 +1,426 @@
+//! Models of ΔT, the difference TT − UT between the uniform time scale of the
+//! solar theory and the rotation of the Earth that sets the civil day.
+//!
+//! The equinox is computed in TT, but the day containing it is counted in UT,
+//! so an equinox-based calendar such as [`QDate`](crate::QDate) depends on
+//! the model: ΔT grows to hours a few millennia away from the present, and
+//! deciding on which side of Paris midnight an equinox falls is then up to
+//! the model as much as to the Sun.
+
+use crate::{
+    astronomical_date::{self, AstronomicalDate},
+    astronomy,
+    date::{self, DateError, DateResult},
+};
+
+/// A model of ΔT.
+pub trait DeltaT {
+    /// Returns ΔT in seconds at the decimal Gregorian year `year`.
+    fn delta_t(&self, year: f64) -> f64;
+
+    /// Returns the Julian Day Number of 1 Vendémiaire of `frc_year`, the day
+    /// in Paris mean time containing the autumn equinox under this model.
+    /// Years from An -20000 to An 20001 are supported, the span over which
+    /// [`AstronomicalDate`] checks the solar theory; others are rejected.
+    fn new_year_day(&self, frc_year: i32) -> DateResult<i64> {
+        if !(astronomical_date::MIN_YEAR..=astronomical_date::MAX_YEAR + 1).contains(&frc_year) {
+            return Err(date::out_of_range::<AstronomicalDate>());
+        }
+
+        Ok(astronomy::equinox_day(frc_year + 1791, self))
+    }
+
+    /// Returns whether `frc_year` is a leap year under this model, i.e.
+    /// whether 366 days separate its equinox from the next. Years outside of
+    /// the range of [`AstronomicalDate`] are rejected.
+    fn leap_year(&self, frc_year: i32) -> DateResult<bool> {
+        let start = self.new_year_day(frc_year)?;
+        let end = self.new_year_day(frc_year + 1)?;
+
+        Ok(end - start == 366)
+    }
+}
+
+/// The polynomial expressions of Espenak and Meeus, fitted to observations
+/// from -500 to 2005 and extrapolated to 2150, with the parabola of Morrison
+/// and Stephenson outside of that span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EspenakMeeus;
+
+impl DeltaT for EspenakMeeus {
+    fn delta_t(&self, year: f64) -> f64 {
+        let polynomial = |t: f64, coefficients: &[f64]| {
+            coefficients
+                .iter()
+                .rev()
+                .fold(0.0, |sum, coefficient| sum * t + coefficient)
+        };
+
+        match year {
+            y if y < -500.0 => MorrisonStephenson.delta_t(y),
+            y if y < 500.0 => polynomial(
+                y / 100.0,
+                &[
+                    10_583.6,
+                    -1_014.41,
+                    33.783_11,
+                    -5.952_053,
+                    -0.179_845_2,
+                    0.022_174_192,
+                    0.009_031_652_1,
+                ],
+            ),
+            y if y < 1600.0 => polynomial(
+                (y - 1000.0) / 100.0,
+                &[
+                    1_574.2,
+                    -556.01,
+                    71.234_72,
+                    0.319_781,
+                    -0.850_346_3,
+                    -0.005_050_998,
+                    0.008_357_207_3,
+                ],
+            ),
+            y if y < 1700.0 => polynomial(y - 1600.0, &[120.0, -0.980_8, -0.015_32, 1.0 / 7_129.0]),
+            y if y < 1800.0 => polynomial(
+                y - 1700.0,
+                &[
+                    8.83,
+                    0.160_3,
+                    -0.005_928_5,
+                    0.000_133_36,
+                    -1.0 / 1_174_000.0,
+                ],
+            ),
+            y if y < 1860.0 => polynomial(
+                y - 1800.0,
+                &[
+                    13.72,
+                    -0.332_447,
+                    0.006_861_2,
+                    0.004_111_6,
+                    -0.000_374_36,
+                    0.000_012_127_2,
+                    -0.000_000_169_9,
+                    0.000_000_000_875,
+                ],
+            ),
+            y if y < 1900.0 => polynomial(
+                y - 1860.0,
+                &[
+                    7.62,
+                    0.573_7,
+                    -0.251_754,
+                    0.016_806_68,
+                    -0.000_447_362_4,
+                    1.0 / 233_174.0,
+                ],
+            ),
+            y if y < 1920.0 => polynomial(
+                y - 1900.0,
+                &[-2.79, 1.494_119, -0.059_893_9, 0.006_196_6, -0.000_197],
+            ),
+            y if y < 1941.0 => polynomial(y - 1920.0, &[21.2, 0.844_93, -0.0761, 0.002_093_6]),
+            y if y < 1961.0 => polynomial(y - 1950.0, &[29.07, 0.407, -1.0 / 233.0, 1.0 / 2_547.0]),
+            y if y < 1986.0 => polynomial(y - 1975.0, &[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0]),
+            y if y < 2005.0 => polynomial(
+                y - 2000.0,
+                &[
+                    63.86,
+                    0.334_5,
+                    -0.060_374,
+                    0.001_727_5,
+                    0.000_651_814,
+                    0.000_023_735_99,
+                ],
+            ),
+            y if y < 2050.0 => polynomial(y - 2000.0, &[62.92, 0.322_17, 0.005_589]),
+            y if y < 2150.0 => MorrisonStephenson.delta_t(y) - 0.562_8 * (2150.0 - y),
+            y => MorrisonStephenson.delta_t(y),
+        }
+    }
+}
+
+/// The long-term parabola of Morrison and Stephenson (2004),
+/// ΔT = −20 + 32 u² seconds with u counted in centuries from 1820.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MorrisonStephenson;
+
+impl DeltaT for MorrisonStephenson {
+    fn delta_t(&self, year: f64) -> f64 {
+        let u = (year - 1820.0) / 100.0;
+
+        -20.0 + 32.0 * u * u
+    }
+}
+
+/// A user-supplied table of ΔT, interpolated linearly between its entries.
+/// Before the first and after the last entry, it follows the parabola of
+/// [`MorrisonStephenson`] shifted to meet the table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeltaTTable {
+    entries: Vec<(f64, f64)>,
+}
+
+impl DeltaTTable {
+    /// Constructs a table from `(year, seconds)` entries, which must be
+    /// finite, sorted by strictly increasing year and not empty.
+    pub fn new(entries: Vec<(f64, f64)>) -> DateResult<Self> {
+        let finite = entries
+            .iter()
+            .all(|(year, seconds)| year.is_finite() && seconds.is_finite());
+        let sorted = entries.windows(2).all(|pair| pair[0].0 < pair[1].0);
+
+        if entries.is_empty() || !finite || !sorted {
+            return Err(DateError::InvalidDeltaT);
+        }
+
+        Ok(DeltaTTable { entries })
+    }
+
+    /// Returns the `(year, seconds)` entries of the table.
+    pub fn entries(&self) -> &[(f64, f64)] {
+        &self.entries
+    }
+}
+
+impl DeltaT for DeltaTTable {
+    fn delta_t(&self, year: f64) -> f64 {
+        let first = self.entries[0];
+        let last = self.entries[self.entries.len() - 1];
+
+        if year <= first.0 {
+            return extrapolate(first, year);
+        }
+
+        if year >= last.0 {
+            return extrapolate(last, year);
+        }
+
+        let index = self
+            .entries
+            .partition_point(|(entry_year, _)| *entry_year <= year);
+        let (start_year, start_seconds) = self.entries[index - 1];
+        let (end_year, end_seconds) = self.entries[index];
+
+        start_seconds
+            + (end_seconds - start_seconds) * (year - start_year) / (end_year - start_year)
+    }
+}
+/// Follows the parabola of [`MorrisonStephenson`] from `year`, shifted to meet
+/// the `(year, seconds)` entry at the edge of a table.
+fn extrapolate((edge_year, edge_seconds): (f64, f64), year: f64) -> f64 {
+    edge_seconds + MorrisonStephenson.delta_t(year) - MorrisonStephenson.delta_t(edge_year)
+}
+
+/// First Gregorian year of [`CALIBRATED`]
+const CALIBRATED_START: i32 = -13_200;
+
+/// Years between two entries of [`CALIBRATED`]
+const CALIBRATED_STEP: i32 = 100;
+
+/// ΔT in seconds every century from [`CALIBRATED_START`], fitted so that
+/// every equinox of the table behind [`QDate`](crate::QDate) falls on the
+/// same day.
+const CALIBRATED: [i32; 305] = [
+    791402, 780515, 765598, 757669, 745520, 734004, 724296, 711036, 701428, 690610, 680243, 669789,
+    658897, 647911, 637952, 628424, 618135, 608544, 598365, 590579, 580492, 570695, 560114, 551824,
+    544722, 533308, 526091, 514614, 507976, 496498, 490727, 480950, 472089, 464095, 455411, 446908,
+    439291, 430667, 423208, 414631, 407629, 398732, 391534, 383641, 375414, 369636, 361919, 354413,
+    347959, 339610, 332944, 326616, 319051, 312116, 306310, 298936, 293643, 285974, 280631, 274167,
+    268014, 262743, 255622, 251751, 244526, 238252, 232967, 228180, 222161, 216761, 211465, 205718,
+    200277, 195018, 190606, 184857, 181137, 174605, 170371, 166543, 160703, 156839, 152566, 147039,
+    142439, 138713, 134855, 130206, 125873, 121326, 117433, 114050, 110463, 105107, 102999, 98118,
+    95091, 91499, 88013, 84467, 81614, 78041, 75420, 71576, 68721, 65336, 62717, 60431, 57401,
+    54279, 51675, 49134, 46819, 44696, 41940, 40080, 37577, 35729, 33164, 31281, 29370, 27251,
+    25415, 23642, 21934, 20169, 18668, 17121, 15520, 14068, 12783, 11630, 10576, 9590, 8634, 7674,
+    6692, 5703, 4732, 3810, 2963, 2286, 1644, 1086, 734, 490, 349, 242, 119, 9, 13, -2, 64, 204,
+    444, 719, 1059, 1448, 1647, 2286, 3058, 3717, 4441, 5229, 6080, 6996, 7976, 9019, 10127, 11299,
+    12534, 13834, 15198, 16489, 17856, 19423, 21128, 22684, 24532, 26535, 28411, 30351, 32354,
+    34422, 36554, 38749, 41009, 43333, 45720, 48055, 50672, 53267, 55853, 58448, 61381, 64226,
+    67126, 70089, 73117, 76209, 79221, 82565, 85868, 89215, 92627, 96103, 99642, 103246, 106914,
+    110645, 114441, 118300, 122224, 126212, 130263, 134379, 138559, 142802, 147109, 151379, 155914,
+    160017, 164654, 169596, 173985, 178775, 183518, 188759, 193478, 198274, 203794, 208934, 214137,
+    218354, 224178, 229513, 235104, 239649, 245042, 250660, 255992, 262249, 268191, 272511, 278653,
+    284789, 289826, 296770, 302725, 307078, 314197, 320322, 326623, 333099, 338682, 345404, 351771,
+    357594, 364097, 369887, 377501, 383816, 390626, 396045, 404284, 410324, 416857, 423705, 431055,
+    436597, 445668, 450176, 458439, 463580, 471481, 480458, 484912, 493545, 501768, 505550, 517279,
+    521737, 530454, 537410, 541901, 554479, 557366, 567507, 573628, 580497, 589192, 595873, 604434,
+    610738, 618657, 626275, 633733, 642049, 649858, 655779, 664181, 672478, 679766, 686909, 696824,
+];
+
+/// An effective ΔT calibrated against the equinox table behind
+/// [`QDate`](crate::QDate), interpolated linearly between centuries from
+/// 13200 BC to AD 17200 and following the parabola of [`MorrisonStephenson`]
+/// beyond. This is the model used by
+/// [`AstronomicalDate`](crate::AstronomicalDate).
+///
+/// The table was computed with the full VSOP87 theory and its own ΔT, which
+/// the truncated theory used here and the published models cannot reproduce
+/// to the minute. This model is not a measure of the rotation of the Earth: it
+/// absorbs the difference between the two computations, so that the
+/// equinoxes land on the same days as in the table. It stays within a few
+/// minutes of [`EspenakMeeus`] over the past three millennia and drifts up
+/// to about 20 hours from it at the ends of the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Calibrated;
+
+impl DeltaT for Calibrated {
+    fn delta_t(&self, year: f64) -> f64 {
+        let entry = |index: usize| {
+            (
+                f64::from(CALIBRATED_START + index as i32 * CALIBRATED_STEP),
+                f64::from(CALIBRATED[index]),
+            )
+        };
+
+        let last = CALIBRATED.len() - 1;
+        let position = (year - f64::from(CALIBRATED_START)) / f64::from(CALIBRATED_STEP);
+
+        if position <= 0.0 {
+            return extrapolate(entry(0), year);
+        }
+
+        if position >= last as f64 {
+            return extrapolate(entry(last), year);
+        }
+
+        let index = position.floor() as usize;
+        let (start, end) = (entry(index).1, entry(index + 1).1);
+
+        start + (end - start) * (position - index as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AstronomicalDate, FrcDate};
+
+    /// The parabola of Stephenson, Morrison and Hohenkerk (2016), sampled
+    /// every 500 years.
+    fn stephenson_2016() -> DeltaTTable {
+        let entries = (-26..=34)
+            .map(|n| {
+                let year = f64::from(n) * 500.0;
+                let u = (year - 1825.0) / 100.0;
+
+                (year, -320.0 + 32.5 * u * u)
+            })
+            .collect();
+
+        DeltaTTable::new(entries).unwrap()
+    }
+
+    #[test]
+    fn models() {
+        assert!((EspenakMeeus.delta_t(2000.0) - 63.86).abs() < 0.01);
+        assert!((EspenakMeeus.delta_t(1800.0) - 13.72).abs() < 0.01);
+        assert_eq!(MorrisonStephenson.delta_t(1820.0), -20.0);
+        assert_eq!(
+            EspenakMeeus.delta_t(-1000.0),
+            MorrisonStephenson.delta_t(-1000.0)
+        );
+        assert_eq!(
+            EspenakMeeus.delta_t(3000.0),
+            MorrisonStephenson.delta_t(3000.0)
+        );
+
+        assert!((Calibrated.delta_t(2000.0) - EspenakMeeus.delta_t(2000.0)).abs() < 1.0);
+        assert_eq!(Calibrated.delta_t(-13_100.0), 780_515.0);
+        assert_eq!(Calibrated.delta_t(-13_150.0), (791_402.0 + 780_515.0) / 2.0);
+
+        let shift = 791_402.0 - MorrisonStephenson.delta_t(-13_200.0);
+        assert!(
+            (Calibrated.delta_t(-14_000.0) - MorrisonStephenson.delta_t(-14_000.0) - shift).abs()
+                < 1e-6
+        );
+    }
+
+    #[test]
+    fn table() {
+        let table = DeltaTTable::new(vec![(2000.0, 64.0), (2020.0, 69.0)]).unwrap();
+
+        assert_eq!(table.delta_t(2000.0), 64.0);
+        assert_eq!(table.delta_t(2010.0), 66.5);
+        assert_eq!(table.delta_t(2020.0), 69.0);
+
+        let shift = 69.0 - MorrisonStephenson.delta_t(2020.0);
+        assert_eq!(
+            table.delta_t(2500.0),
+            MorrisonStephenson.delta_t(2500.0) + shift
+        );
+
+        assert!(matches!(
+            DeltaTTable::new(vec![]),
+            Err(DateError::InvalidDeltaT)
+        ));
+        assert!(matches!(
+            DeltaTTable::new(vec![(2020.0, 69.0), (2000.0, 64.0)]),
+            Err(DateError::InvalidDeltaT)
+        ));
+        assert!(matches!(
+            DeltaTTable::new(vec![(2000.0, f64::NAN)]),
+            Err(DateError::InvalidDeltaT)
+        ));
+    }
+
+    #[test]
+    fn leap_years() {
+        for year in 1..300 {
+            assert_eq!(
+                EspenakMeeus.leap_year(year).unwrap(),
+                AstronomicalDate::leap_year(year)
+            );
+        }
+
+        // the two models only differ between -500 and 2150, where they decide
+        // the start of six years differently
+        let differ: Vec<i32> = (-2500..=360)
+            .filter(|&year| {
+                EspenakMeeus.new_year_day(year).unwrap()
+                    != MorrisonStephenson.new_year_day(year).unwrap()
+            })
+            .collect();
+        assert_eq!(differ, vec![-1309, -1012, -913, -847, -814, -331]);
+    }
+
+    #[test]
+    fn far_future_sensitivity() {
+        let table = stephenson_2016();
+
+        let differ = (13_000..13_100)
+            .filter(|&year| EspenakMeeus.leap_year(year).unwrap() != table.leap_year(year).unwrap())
+            .count();
+
+        // the two models are about two hours apart by then
+        assert_eq!(differ, 16);
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(
+            Calibrated.new_year_day(-20_000).unwrap(),
+            AstronomicalDate::MIN_JULIAN_DAY
+        );
+        assert_eq!(
+            Calibrated.new_year_day(20_001).unwrap(),
+            AstronomicalDate::MAX_JULIAN_DAY + 1
+        );
+        assert!(EspenakMeeus.leap_year(20_000).is_ok());
+
+        assert!(matches!(
+            EspenakMeeus.new_year_day(20_002),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            EspenakMeeus.leap_year(-20_001),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            MorrisonStephenson.leap_year(i32::MAX),
+            Err(DateError::OutOfRange { .. })
+        ));
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt::Display;
+
+use time::{Duration, OffsetDateTime, UtcOffset};
+
+use crate::{
+    astronomy,
+    date::{self, DateError, DateResult},
+    delta_t::Calibrated,
+};
+
+/// Paris mean time, 9 min 21 s ahead of UT, in which the days of the FRC
+/// start and end.
+pub const PARIS_MEAN_TIME: UtcOffset =
+    match UtcOffset::from_whole_seconds(astronomy::PARIS_MEAN_TIME_OFFSET) {
+        Ok(offset) => offset,
+        Err(_) => panic!("the offset is less than a day"),
+    };
+
+/// Julian Date of the Unix epoch
+const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+
+/// Returns the instant of the autumn equinox of `gregorian_year`, in UTC and
+/// rounded to the second, with ΔT following the [`Calibrated`] model. Convert
+/// it with [`PARIS_MEAN_TIME`] for the time at Paris.
+pub fn autumn_equinox(gregorian_year: i32) -> DateResult<OffsetDateTime> {
+    let jd = astronomy::autumn_equinox_ut(gregorian_year, &Calibrated);
+    let seconds = ((jd - UNIX_EPOCH_JD) * 86_400.0).round() as i64;
+
+    OffsetDateTime::from_unix_timestamp(seconds).map_err(|_| DateError::GregorianOutOfRange)
+}
+
+/// Returns when and why 1 Vendémiaire of `frc_year` falls where it does in
+/// [`AstronomicalDate`](crate::AstronomicalDate) and, within its table,
+/// [`QDate`](crate::QDate).
+pub fn new_year_day(frc_year: i32) -> DateResult<NewYearDay> {
+    let gregorian_year = frc_year
+        .checked_add(1791)
+        .ok_or(DateError::GregorianOutOfRange)?;
+
+    let jdn = i32::try_from(astronomy::equinox_day(gregorian_year, &Calibrated))
+        .map_err(|_| DateError::GregorianOutOfRange)?;
+
+    Ok(NewYearDay {
+        year: frc_year,
+        date: time::Date::from_julian_day(jdn).map_err(|_| DateError::GregorianOutOfRange)?,
+        equinox: autumn_equinox(gregorian_year)?.to_offset(PARIS_MEAN_TIME),
+    })
+}
+
+/// The first day of an FRC year along with the autumn equinox that makes it
+/// so: the year starts on the day, in Paris mean time, containing the
+/// equinox.
+///
+/// A year is a leap year when the following new year day falls 366 days
+/// later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NewYearDay {
+    year: i32,
+    date: time::Date,
+    equinox: OffsetDateTime,
+}
+
+impl Display for NewYearDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let since_midnight = self.since_midnight();
+        let (hour, minute, second) = self.equinox.to_hms();
+
+        write!(
+            f,
+            "An {} starts on {}: the equinox falls at {:02}:{:02}:{:02} Paris mean time, {}h {:02}m {:02}s after midnight",
+            date::format_year(self.year),
+            self.date,
+            hour,
+            minute,
+            second,
+            since_midnight.whole_hours(),
+            since_midnight.whole_minutes() % 60,
+            since_midnight.whole_seconds() % 60,
+        )
+    }
+}
+
+impl NewYearDay {
+    /// Returns the FRC year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the Gregorian date of 1 Vendémiaire.
+    pub fn date(&self) -> time::Date {
+        self.date
+    }
+
+    /// Returns the instant of the equinox in Paris mean time.
+    pub fn equinox(&self) -> OffsetDateTime {
+        self.equinox
+    }
+
+    /// Returns the time from the Paris midnight starting 1 Vendémiaire to the
+    /// equinox.
+    pub fn since_midnight(&self) -> Duration {
+        self.equinox - self.date.midnight().assume_offset(PARIS_MEAN_TIME)
+    }
+
+    /// Returns the time from the equinox to the Paris midnight ending
+    /// 1 Vendémiaire.
+    pub fn until_midnight(&self) -> Duration {
+        Duration::DAY - self.since_midnight()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cal, AstronomicalDate, FrcDate, QDate};
+
+    #[test]
+    fn equinox_instants() {
+        // 12h 43m 36s UTC
+        let equinox = autumn_equinox(2024).unwrap();
+        let expected = time::Date::from_calendar_date(2024, time::Month::September, 22)
+            .unwrap()
+            .with_hms(12, 43, 36)
+            .unwrap()
+            .assume_utc();
+        assert!((equinox - expected).abs() < Duration::minutes(2));
+        assert_eq!(equinox.offset(), UtcOffset::UTC);
+
+        let paris = equinox.to_offset(PARIS_MEAN_TIME);
+        assert_eq!(paris, equinox);
+        assert_eq!(paris.time() - equinox.time(), Duration::seconds(561));
+    }
+
+    #[test]
+    fn new_year_days() {
+        let first = new_year_day(1).unwrap();
+        assert_eq!(
+            first.date(),
+            time::Date::from_calendar_date(1792, time::Month::September, 22).unwrap()
+        );
+        assert_eq!(first.since_midnight().whole_hours(), 9);
+        assert_eq!(
+            first.since_midnight() + first.until_midnight(),
+            Duration::DAY
+        );
+        assert!(first
+            .to_string()
+            .starts_with("An I starts on 1792-09-22: the equinox falls at 09:"));
+
+        for year in 1..300 {
+            let new_year = new_year_day(year).unwrap();
+            let next = new_year_day(year + 1).unwrap();
+
+            assert_eq!(
+                i64::from(new_year.date().to_julian_day()),
+                AstronomicalDate::from_frc(year, 1, 1).unwrap().julian_day()
+            );
+            assert_eq!(new_year.equinox().date(), new_year.date());
+            assert_eq!(
+                (next.date() - new_year.date()).whole_days() == 366,
+                QDate::leap_year(year)
+            );
+        }
+    }
+
+    #[test]
+    fn matches_equinox_table() {
+        for index in 0..cal::LEAP_YEARS.len() {
+            let year = cal::START_YEAR + index as i32;
+
+            match new_year_day(year) {
+                Ok(new_year) => assert_eq!(
+                    i64::from(new_year.date().to_julian_day()),
+                    QDate::from_frc(year, 1, 1).unwrap().julian_day(),
+                    "year {}",
+                    year
+                ),
+                // the Gregorian calendar of `time` stops at ±9999
+                Err(error) => {
+                    assert!(matches!(error, DateError::GregorianOutOfRange));
+                    assert!((year + 1791).abs() > 9999, "year {}", year);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(matches!(
+            autumn_equinox(20_000),
+            Err(DateError::GregorianOutOfRange)
+        ));
+        assert!(matches!(
+            new_year_day(i32::MAX),
+            Err(DateError::GregorianOutOfRange)
+        ));
+    }
+}
@@ -0,0 +1,203 @@
+use std::{fmt::Write, str::FromStr};
+
+use crate::{
+    date::{self, DateError, DateResult, FrcDate},
+    day_kind::DayKind,
+    weekday::Weekday,
+};
+
+/// A single component of a [`FormatDescription`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatItem {
+    /// Text copied verbatim into the output.
+    Literal(String),
+    /// `%A`: the day of the décade, or the fête of a Complémentaires day.
+    Weekday,
+    /// `%d`: the day of the month.
+    Day,
+    /// `%B`: the name of the month.
+    MonthName,
+    /// `%m`: the number of the month, 13 being the Complémentaires.
+    Month,
+    /// `%Y`: the year in Arabic numerals.
+    Year,
+    /// `%y`: the year in Roman numerals, falling back to Arabic numerals for
+    /// years that have none.
+    RomanYear,
+    /// `%j`: the day of the year.
+    DayOfYear,
+    /// `%D`: the décade of the year, from 1 to 36. Empty for the
+    /// Complémentaires, which are not part of any décade.
+    Decade,
+    /// `%R`: the French name of the rural day. Empty for the Complémentaires.
+    RuralDay,
+    /// `%f`: the fête of the day: the rural day, or the name of the
+    /// Complémentaires day.
+    Fête,
+}
+
+/// A parsed format string, e.g. `%A %d %B an %Y`. Parsing validates every
+/// specifier up front, so a description can be built once and reused to
+/// format any number of dates.
+///
+/// `%%` produces a literal `%`. Numbers are not padded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatDescription {
+    items: Vec<FormatItem>,
+}
+
+impl FromStr for FormatDescription {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FormatDescription::parse(s)
+    }
+}
+
+impl FormatDescription {
+    pub fn parse(description: &str) -> DateResult<Self> {
+        let mut items = vec![];
+        let mut literal = String::new();
+        let mut chars = description.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                literal.push(c);
+                continue;
+            }
+
+            let item = match chars.next() {
+                Some('%') => {
+                    literal.push('%');
+                    continue;
+                }
+                Some('A') => FormatItem::Weekday,
+                Some('d') => FormatItem::Day,
+                Some('B') => FormatItem::MonthName,
+                Some('m') => FormatItem::Month,
+                Some('Y') => FormatItem::Year,
+                Some('y') => FormatItem::RomanYear,
+                Some('j') => FormatItem::DayOfYear,
+                Some('D') => FormatItem::Decade,
+                Some('R') => FormatItem::RuralDay,
+                Some('f') => FormatItem::Fête,
+                _ => return Err(DateError::InvalidFormatDescription),
+            };
+
+            if !literal.is_empty() {
+                items.push(FormatItem::Literal(std::mem::take(&mut literal)));
+            }
+
+            items.push(item);
+        }
+
+        if !literal.is_empty() {
+            items.push(FormatItem::Literal(literal));
+        }
+
+        Ok(FormatDescription { items })
+    }
+
+    pub fn items(&self) -> &[FormatItem] {
+        &self.items
+    }
+
+    pub(crate) fn format<D: FrcDate>(&self, date: &D) -> String {
+        let mut s = String::new();
+
+        for item in &self.items {
+            // writing to a String cannot fail
+            let _ = match item {
+                FormatItem::Literal(literal) => write!(s, "{}", literal),
+                FormatItem::Weekday => match date.weekday() {
+                    Weekday::Ordinary(day) => write!(s, "{}", day),
+                    Weekday::Complimentary(day) => write!(s, "{}", day),
+                },
+                FormatItem::Day => write!(s, "{}", date.day()),
+                FormatItem::MonthName => write!(s, "{}", date.month()),
+                FormatItem::Month => write!(s, "{}", date.month_int()),
+                FormatItem::Year => write!(s, "{}", date.year()),
+                FormatItem::RomanYear => write!(s, "{}", date::format_year(date.year())),
+                FormatItem::DayOfYear => write!(s, "{}", date.day_of_year()),
+                FormatItem::Decade => match date.decade_of_year() {
+                    Some(decade) => write!(s, "{}", decade),
+                    None => Ok(()),
+                },
+                FormatItem::RuralDay => match date.rural_day() {
+                    Some((name, _)) => write!(s, "{}", name),
+                    None => Ok(()),
+                },
+                FormatItem::Fête => match (date.kind(), date.rural_day()) {
+                    (DayKind::Complementary(day), _) => write!(s, "{}", day),
+                    (DayKind::Ordinary(_), Some((name, _))) => write!(s, "{}", name),
+                    (DayKind::Ordinary(_), None) => Ok(()),
+                },
+            };
+        }
+
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Date, QDate};
+
+    #[test]
+    fn parse() {
+        let description = FormatDescription::parse("%A %d %B an %Y (100%%)").unwrap();
+
+        assert_eq!(
+            description.items(),
+            &[
+                FormatItem::Weekday,
+                FormatItem::Literal(" ".to_string()),
+                FormatItem::Day,
+                FormatItem::Literal(" ".to_string()),
+                FormatItem::MonthName,
+                FormatItem::Literal(" an ".to_string()),
+                FormatItem::Year,
+                FormatItem::Literal(" (100%)".to_string()),
+            ]
+        );
+
+        for invalid in &["%", "%Q", "an %"] {
+            assert!(
+                FormatDescription::parse(invalid).is_err(),
+                "{} should not parse",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn format() {
+        let date = Date::new(QDate::from_frc(8, 2, 18).unwrap());
+
+        let cases = &[
+            ("%A %d %B an %Y", "octidi 18 Brumaire an 8"),
+            ("%d/%m/%y", "18/2/VIII"),
+            ("%j", "48"),
+            ("%D", "5"),
+            ("%R|%f", "Dentelaire|Dentelaire"),
+            ("100%%", "100%"),
+        ];
+
+        for &(description, expected) in cases {
+            let description: FormatDescription = description.parse().unwrap();
+            assert_eq!(date.format(&description), expected);
+        }
+    }
+
+    #[test]
+    fn format_complementaires() {
+        let date = Date::new(QDate::from_frc(2, 13, 2).unwrap());
+        let description = FormatDescription::parse("%A|%d %B|%D|%R|%f|%j").unwrap();
+
+        assert_eq!(
+            date.format(&description),
+            "La Fête du Génie|2 Complémentaires|||La Fête du Génie|362"
+        );
+    }
+}
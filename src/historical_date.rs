@@ -0,0 +1,245 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::date::{self, Date, DateError, DateResult, FrcDate, EPOCH_JDN};
+
+/// The sextile years observed while the calendar was in use.
+const SEXTILE_YEARS: [i32; 3] = [3, 7, 11];
+
+/// Represents an FRC date as printed on documents of the time. The calendar
+/// was in legal use from 1 Vendémiaire An I (22 September 1792) until it was
+/// abolished after 10 Nivôse An XIV (31 December 1805), during which years
+/// III, VII and XI were sextile. Dates outside of that span fail with
+/// [`DateError::OutOfRange`] rather than following any later proposal; use
+/// [`RommeDate`](crate::RommeDate) or [`QDate`](crate::QDate) for those.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HistoricalDate {
+    jdn: i32,
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Display for HistoricalDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Date::new(*self).fmt(f)
+    }
+}
+
+impl FromStr for HistoricalDate {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> DateResult<HistoricalDate> {
+        s.parse::<Date<Self>>().map(Date::into_inner)
+    }
+}
+
+impl FrcDate for HistoricalDate {
+    const MIN_JULIAN_DAY: i64 = HistoricalDate::MIN.jdn as i64;
+    const MAX_JULIAN_DAY: i64 = HistoricalDate::MAX.jdn as i64;
+
+    fn month_int(&self) -> u8 {
+        self.month
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    fn leap_year(year: i32) -> bool {
+        SEXTILE_YEARS.contains(&year)
+    }
+
+    fn julian_day(&self) -> i64 {
+        self.jdn.into()
+    }
+
+    fn from_julian_day(jdn: i64) -> DateResult<Self> {
+        if !(Self::MIN_JULIAN_DAY..=Self::MAX_JULIAN_DAY).contains(&jdn) {
+            return Err(date::out_of_range::<Self>());
+        }
+
+        let days = jdn - EPOCH_JDN;
+        let year = (1..=HistoricalDate::MAX.year)
+            .rev()
+            .find(|&year| HistoricalDate::days_before_year(year) <= days)
+            .expect("the first year starts at the epoch");
+        let day_of_year = days - HistoricalDate::days_before_year(year);
+
+        Ok(Self {
+            jdn: jdn as i32,
+            year,
+            month: (day_of_year / 30 + 1) as u8,
+            day: (day_of_year % 30 + 1) as u8,
+        })
+    }
+
+    fn from_frc(year: i32, month: u8, day: u8) -> DateResult<Self> {
+        if !(HistoricalDate::MIN.year..=HistoricalDate::MAX.year).contains(&year) {
+            return Err(date::out_of_range::<Self>());
+        }
+
+        if !date::is_valid_day(month, day, HistoricalDate::leap_year(year)) {
+            return Err(DateError::InvalidDate);
+        }
+
+        let day_of_year = (i64::from(month) - 1) * 30 + i64::from(day) - 1;
+        let days = HistoricalDate::days_before_year(year) + day_of_year;
+
+        HistoricalDate::from_julian_day(EPOCH_JDN + days)
+    }
+}
+
+date::impl_day_arithmetic!(HistoricalDate);
+
+impl HistoricalDate {
+    /// The first day of the calendar, 1 Vendémiaire An I (22 September 1792).
+    pub const MIN: HistoricalDate = HistoricalDate {
+        jdn: EPOCH_JDN as i32,
+        year: 1,
+        month: 1,
+        day: 1,
+    };
+
+    /// The last day the calendar was in use, 10 Nivôse An XIV (31 December
+    /// 1805).
+    pub const MAX: HistoricalDate = HistoricalDate {
+        jdn: 2_380_687,
+        year: 14,
+        month: 4,
+        day: 10,
+    };
+
+    pub fn from_gregorian_date(year: i32, month: u8, day: u8) -> DateResult<Self> {
+        let month = time::Month::try_from(month).map_err(|_| DateError::InvalidDate)?;
+        let date = time::Date::from_calendar_date(year, month, day)?;
+
+        HistoricalDate::from_julian_day(date.to_julian_day().into())
+    }
+
+    /// Returns the Gregorian date corresponding to this date.
+    pub fn to_gregorian(&self) -> Result<time::Date, DateError> {
+        Ok(time::Date::from_julian_day(self.jdn)?)
+    }
+
+    /// Returns the number of days from 1 Vendémiaire An I to 1 Vendémiaire of
+    /// `year`.
+    fn days_before_year(year: i32) -> i64 {
+        let sextiles = SEXTILE_YEARS
+            .iter()
+            .filter(|&&sextile| sextile < year)
+            .count();
+
+        365 * (i64::from(year) - 1) + sextiles as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HistoricalDate;
+    use crate::{
+        date::{DateError, FrcDate},
+        QDate,
+    };
+
+    #[test]
+    fn historical_equivalences() {
+        let dates = vec![
+            ((1, 1, 1), (1792, 9, 22)),
+            ((2, 11, 9), (1794, 7, 27)),
+            ((3, 13, 6), (1795, 9, 22)),
+            ((4, 1, 1), (1795, 9, 23)),
+            ((5, 1, 1), (1796, 9, 22)),
+            ((8, 1, 1), (1799, 9, 23)),
+            ((8, 2, 18), (1799, 11, 9)),
+            ((12, 1, 1), (1803, 9, 24)),
+            ((13, 1, 1), (1804, 9, 23)),
+            ((14, 4, 10), (1805, 12, 31)),
+        ];
+
+        for ((year, month, day), (gregorian_year, gregorian_month, gregorian_day)) in dates {
+            let date = HistoricalDate::from_frc(year, month, day).unwrap();
+            let gregorian = date.to_gregorian().unwrap();
+
+            assert_eq!(
+                (gregorian.year(), gregorian.month() as u8, gregorian.day()),
+                (gregorian_year, gregorian_month, gregorian_day)
+            );
+            assert_eq!(
+                HistoricalDate::from_gregorian_date(gregorian_year, gregorian_month, gregorian_day)
+                    .unwrap(),
+                date
+            );
+        }
+
+        assert_eq!(
+            HistoricalDate::from_frc(2, 11, 9).unwrap().to_string(),
+            "9 Thermidor An II"
+        );
+    }
+
+    #[test]
+    fn sextile_years() {
+        let sextiles: Vec<i32> = (1..=14)
+            .filter(|&year| HistoricalDate::leap_year(year))
+            .collect();
+        assert_eq!(sextiles, vec![3, 7, 11]);
+        assert!(matches!(
+            HistoricalDate::from_frc(4, 13, 6),
+            Err(DateError::InvalidDate)
+        ));
+    }
+
+    #[test]
+    fn matches_equinox_table() {
+        let mut date = HistoricalDate::MIN;
+
+        while date != HistoricalDate::MAX {
+            let qdate = QDate::from_julian_day(date.julian_day()).unwrap();
+            assert_eq!(
+                (date.year(), date.month_int(), date.day()),
+                (qdate.year(), qdate.month_int(), qdate.day())
+            );
+
+            date = date.succ().unwrap();
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(matches!(
+            HistoricalDate::MAX.succ(),
+            Err(DateError::OutOfRange {
+                min: 2_375_840,
+                max: 2_380_687
+            })
+        ));
+        assert!(matches!(
+            HistoricalDate::MIN.pred(),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            HistoricalDate::from_frc(14, 4, 11),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            HistoricalDate::from_frc(15, 1, 1),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            HistoricalDate::from_frc(0, 1, 1),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            HistoricalDate::from_gregorian_date(1792, 9, 21),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            HistoricalDate::from_gregorian_date(1806, 1, 1),
+            Err(DateError::OutOfRange { .. })
+        ));
+    }
+}
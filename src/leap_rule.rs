@@ -0,0 +1,139 @@
+//! Arithmetic leap-year rules proposed for the FRC, for use with
+//! [`RuleDate`](crate::RuleDate).
+//!
+//! Years are numbered like [`RommeDate`](crate::RommeDate) years: An I starts
+//! on 22 September 1792 and the year before it is year 0.
+
+use std::{fmt::Debug, hash::Hash};
+
+/// A rule deciding which FRC years are leap years, i.e. have a sixth
+/// Complémentaires day. Rules are marker types, hence the derivable bounds.
+pub trait LeapRule: Debug + Copy + Eq + Hash {
+    /// Returns whether `year` is a leap year.
+    fn is_leap(year: i32) -> bool;
+
+    /// Returns the number of leap years from An I up to, but not including,
+    /// `year`. For years before An I this is minus the number of leap years
+    /// from `year` up to An I.
+    fn leap_years_before(year: i32) -> i64;
+
+    /// Returns the number of days from 1 Vendémiaire An I to 1 Vendémiaire of
+    /// `year`.
+    fn days_before_year(year: i32) -> i64 {
+        365 * (i64::from(year) - 1) + Self::leap_years_before(year)
+    }
+}
+
+/// Romme's rule as adopted by [`RommeDate`](crate::RommeDate): every fourth
+/// year is a leap year, except for centuries not divisible by 400.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Romme;
+
+impl LeapRule for Romme {
+    fn is_leap(year: i32) -> bool {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+
+    fn leap_years_before(year: i32) -> i64 {
+        let years = i64::from(year) - 1;
+
+        years.div_euclid(4) - years.div_euclid(100) + years.div_euclid(400)
+    }
+}
+
+/// Romme's rule with the further correction of his original proposal: years
+/// divisible by 4000 are not leap years, for a mean year of 365.24225 days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Romme4000;
+
+impl LeapRule for Romme4000 {
+    fn is_leap(year: i32) -> bool {
+        Romme::is_leap(year) && year % 4000 != 0
+    }
+
+    fn leap_years_before(year: i32) -> i64 {
+        Romme::leap_years_before(year) - (i64::from(year) - 1).div_euclid(4000)
+    }
+}
+
+/// Delambre's continuation of the sextile years actually observed, III, VII
+/// and XI: a year is a leap year when the following one would be under
+/// Romme's rule, so that the sextile day falls shortly before the Gregorian
+/// leap day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Delambre;
+
+impl LeapRule for Delambre {
+    fn is_leap(year: i32) -> bool {
+        year.checked_add(1).is_some_and(Romme::is_leap)
+    }
+
+    fn leap_years_before(year: i32) -> i64 {
+        let years = i64::from(year);
+
+        years.div_euclid(4) - years.div_euclid(100) + years.div_euclid(400)
+    }
+}
+
+/// Von Mädler's rule: every fourth year is a leap year, except for years
+/// divisible by 128, for a mean year of 365.2421875 days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VonMädler;
+
+impl LeapRule for VonMädler {
+    fn is_leap(year: i32) -> bool {
+        year % 4 == 0 && year % 128 != 0
+    }
+
+    fn leap_years_before(year: i32) -> i64 {
+        let years = i64::from(year) - 1;
+
+        years.div_euclid(4) - years.div_euclid(128)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_leap_years_before<R: LeapRule>() {
+        let mut count = 0;
+
+        for year in 1..=20_000 {
+            assert_eq!(R::leap_years_before(year), count, "year {}", year);
+            count += i64::from(R::is_leap(year));
+        }
+
+        let mut count = 0;
+
+        for year in (-20_000..=0).rev() {
+            count -= i64::from(R::is_leap(year));
+            assert_eq!(R::leap_years_before(year), count, "year {}", year);
+        }
+    }
+
+    #[test]
+    fn leap_years_before() {
+        check_leap_years_before::<Romme>();
+        check_leap_years_before::<Romme4000>();
+        check_leap_years_before::<Delambre>();
+        check_leap_years_before::<VonMädler>();
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(Romme::is_leap(4000));
+        assert!(!Romme4000::is_leap(4000));
+        assert!(Romme4000::is_leap(400));
+
+        let sextiles: Vec<i32> = (1..=20).filter(|&year| Delambre::is_leap(year)).collect();
+        assert_eq!(sextiles, vec![3, 7, 11, 15, 19]);
+        assert!(!Delambre::is_leap(99));
+        assert!(Delambre::is_leap(399));
+
+        assert!(VonMädler::is_leap(124));
+        assert!(!VonMädler::is_leap(128));
+        assert!(VonMädler::is_leap(200));
+        assert!(!VonMädler::is_leap(-128));
+    }
+}
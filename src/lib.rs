@@ -1,14 +1,47 @@
 // see: https://github.com/quantum5/qcal/blob/master/common/src/french/index.ts
 
+mod astronomical_date;
+mod astronomy;
 mod cal;
 mod date;
+mod date_time;
+mod day_kind;
+mod decade;
+mod decimal_time;
+mod equinox;
+mod historical_date;
+mod period;
 mod qdate;
+mod range;
 mod romme_date;
+mod rule_date;
+mod year;
+mod year_month;
 
+pub mod delta_t;
+pub mod format;
+pub mod leap_rule;
 pub mod month;
-pub(crate) mod numeral;
+pub mod numeral;
+pub mod prelude;
 pub mod weekday;
 
-pub use date::Date;
+pub use astronomical_date::AstronomicalDate;
+pub use date::{Date, DateError, DateResult, FrcDate, OverflowPolicy};
+pub use date_time::FrcDateTime;
+pub use day_kind::{DayKind, OrdinaryDay};
+pub use decade::Decade;
+pub use decimal_time::DecimalTime;
+pub use equinox::{autumn_equinox, new_year_day, NewYearDay, PARIS_MEAN_TIME};
+pub use historical_date::HistoricalDate;
+pub use period::Period;
 pub use qdate::QDate;
+pub use range::FrcDateRange;
 pub use romme_date::RommeDate;
+pub use rule_date::RuleDate;
+pub use year::FrcYear;
+pub use year_month::YearMonth;
+
+#[cfg(doctest)]
+#[doc = include_str!("../README.md")]
+struct ReadmeDoctests;
@@ -1,6 +1,8 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+use crate::date::DateError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Month {
     Vendémiaire,
     Brumaire,
@@ -41,6 +43,42 @@ impl Display for Month {
     }
 }
 
+impl TryFrom<u8> for Month {
+    type Error = DateError;
+
+    /// Returns the `n`th month of the year, 13 being the Complémentaires.
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        match n {
+            1 => Ok(Month::Vendémiaire),
+            2 => Ok(Month::Brumaire),
+            3 => Ok(Month::Frimaire),
+            4 => Ok(Month::Nivôse),
+            5 => Ok(Month::Pluviôse),
+            6 => Ok(Month::Ventôse),
+            7 => Ok(Month::Germinal),
+            8 => Ok(Month::Floréal),
+            9 => Ok(Month::Prairial),
+            10 => Ok(Month::Messidor),
+            11 => Ok(Month::Thermidor),
+            12 => Ok(Month::Fructidor),
+            13 => Ok(Month::Complémentaires),
+            _ => Err(DateError::InvalidDate),
+        }
+    }
+}
+
+impl FromStr for Month {
+    type Err = DateError;
+
+    /// Parses a month name as printed by `Display`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        (1..=13)
+            .filter_map(|n| Month::try_from(n).ok())
+            .find(|month| month.to_string().to_lowercase() == s.to_lowercase())
+            .ok_or(DateError::ParseError)
+    }
+}
+
 impl Month {
     pub fn value(&self) -> u8 {
         match self {
@@ -95,23 +133,4 @@ impl Month {
             Month::Vendémiaire => Month::Complémentaires,
         }
     }
-
-    pub(crate) fn nth(n: u8) -> Month {
-        match n {
-            1 => Month::Vendémiaire,
-            2 => Month::Brumaire,
-            3 => Month::Frimaire,
-            4 => Month::Nivôse,
-            5 => Month::Pluviôse,
-            6 => Month::Ventôse,
-            7 => Month::Germinal,
-            8 => Month::Floréal,
-            9 => Month::Prairial,
-            10 => Month::Messidor,
-            11 => Month::Thermidor,
-            12 => Month::Fructidor,
-            13 => Month::Complémentaires,
-            _ => unreachable!("invalid month: {}", n),
-        }
-    }
 }
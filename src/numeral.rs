@@ -26,7 +26,7 @@ pub fn to_numeral(value: u16) -> Option<String> {
 
     for &(number, numeral) in NUMERALS {
         while value >= number {
-            value = value - number;
+            value -= number;
             s.push_str(numeral)
         }
     }
@@ -34,9 +34,103 @@ pub fn to_numeral(value: u16) -> Option<String> {
     Some(s)
 }
 
+/// Largest value that can be written with [`to_extended_numeral`].
+pub const MAX_EXTENDED_NUMERAL_VALUE: u32 = 3_999_999;
+
+/// U+0305 COMBINING OVERLINE, the vinculum multiplying a numeral by 1000
+const VINCULUM: char = '\u{305}';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    Upper,
+    Lower,
+}
+
+/// Writes `value` as a Roman numeral, using a vinculum (an overline) over the
+/// thousands for values of 4000 and above, e.g. 15 000 is "X̅V̅". Values below
+/// 4000 are written as by [`to_numeral`]. There is no numeral for zero.
+pub fn to_extended_numeral(value: u32, case: Case) -> Option<String> {
+    if value == 0 || value > MAX_EXTENDED_NUMERAL_VALUE {
+        return None;
+    }
+
+    let numeral = if value <= MAX_NUMERAL_VALUE.into() {
+        to_numeral(value as u16)?
+    } else {
+        let thousands: String = to_numeral((value / 1000) as u16)?
+            .chars()
+            .flat_map(|c| [c, VINCULUM])
+            .collect();
+
+        thousands + &to_numeral((value % 1000) as u16)?
+    };
+
+    match case {
+        Case::Upper => Some(numeral),
+        Case::Lower => Some(numeral.to_lowercase()),
+    }
+}
+
+/// Parses a Roman numeral written in either case, accepting only the
+/// canonical form produced by [`to_extended_numeral`]: "IIII", "VX" or a
+/// mixture of cases are rejected.
+pub fn from_numeral(numeral: &str) -> Option<u32> {
+    let case = if !numeral.chars().any(char::is_lowercase) {
+        Case::Upper
+    } else if !numeral.chars().any(char::is_uppercase) {
+        Case::Lower
+    } else {
+        return None;
+    };
+
+    let upper = numeral.to_uppercase();
+
+    let mut thousands = String::new();
+    let mut rest = upper.as_str();
+
+    while let Some(c) = rest.chars().next() {
+        match rest[c.len_utf8()..].strip_prefix(VINCULUM) {
+            Some(stripped) => {
+                thousands.push(c);
+                rest = stripped;
+            }
+            None => break,
+        }
+    }
+
+    let value = parse_additive(&thousands)?
+        .saturating_mul(1000)
+        .saturating_add(parse_additive(rest)?);
+
+    if to_extended_numeral(value, case).as_deref() != Some(numeral) {
+        return None;
+    }
+
+    Some(value)
+}
+
+/// Sums the symbols of a numeral without checking that they are in
+/// canonical order.
+fn parse_additive(numeral: &str) -> Option<u32> {
+    let mut rest = numeral;
+    let mut value: u32 = 0;
+
+    for &(number, symbol) in NUMERALS {
+        while let Some(stripped) = rest.strip_prefix(symbol) {
+            value = value.saturating_add(number.into());
+            rest = stripped;
+        }
+    }
+
+    rest.is_empty().then_some(value)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::to_numeral;
+    use super::{
+        from_numeral, to_extended_numeral, to_numeral, Case, MAX_EXTENDED_NUMERAL_VALUE,
+        MAX_NUMERAL_VALUE,
+    };
 
     #[test]
     fn test_values() {
@@ -78,4 +172,81 @@ mod tests {
     fn test_invalid_values() {
         assert!(to_numeral(4000).is_none());
     }
+
+    #[test]
+    fn test_extended_values() {
+        let test_cases = &[
+            (1, "I"),
+            (3999, "MMMCMXCIX"),
+            (4000, "I\u{305}V\u{305}"),
+            (4001, "I\u{305}V\u{305}I"),
+            (15_399, "X\u{305}V\u{305}CCCXCIX"),
+            (
+                3_999_999,
+                "M\u{305}M\u{305}M\u{305}C\u{305}M\u{305}X\u{305}C\u{305}I\u{305}X\u{305}CMXCIX",
+            ),
+        ];
+
+        for &(value, expected) in test_cases {
+            assert_eq!(
+                to_extended_numeral(value, Case::Upper),
+                Some(expected.to_string())
+            );
+            assert_eq!(from_numeral(expected), Some(value));
+        }
+
+        assert!(to_extended_numeral(0, Case::Upper).is_none());
+        assert!(to_extended_numeral(MAX_EXTENDED_NUMERAL_VALUE + 1, Case::Upper).is_none());
+    }
+
+    #[test]
+    fn test_lowercase() {
+        assert_eq!(
+            to_extended_numeral(233, Case::Lower),
+            Some("ccxxxiii".to_string())
+        );
+        assert_eq!(
+            to_extended_numeral(4001, Case::Lower),
+            Some("i\u{305}v\u{305}i".to_string())
+        );
+        assert_eq!(from_numeral("ccxxxiii"), Some(233));
+        assert!(from_numeral("CCxxxiii").is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        for value in (1..=MAX_EXTENDED_NUMERAL_VALUE).step_by(37) {
+            let numeral = to_extended_numeral(value, Case::Upper).unwrap();
+            assert_eq!(from_numeral(&numeral), Some(value));
+        }
+
+        for value in 1..=MAX_NUMERAL_VALUE {
+            assert_eq!(
+                from_numeral(&to_numeral(value).unwrap()),
+                Some(value.into())
+            );
+        }
+
+        let invalid = &[
+            "",
+            "IIII",
+            "VX",
+            "IC",
+            "MMMM",
+            "ABC",
+            "XIIV",
+            "I\u{305}",
+            "I\u{305}V\u{305}M",
+            "V\u{305}I\u{305}I\u{305}I\u{305}I\u{305}",
+            "\u{305}I",
+        ];
+
+        for invalid in invalid.iter().copied().chain([&*"M".repeat(100)]) {
+            assert!(
+                from_numeral(invalid).is_none(),
+                "{} should not parse",
+                invalid
+            );
+        }
+    }
 }
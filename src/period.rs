@@ -0,0 +1,170 @@
+use std::fmt::Display;
+
+use crate::date::FrcDate;
+
+/// A duration expressed in Republican units. A year is made up of thirteen
+/// months, the last of which is the five or six days of the Complémentaires,
+/// so `months` ranges from 0 to 12.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Period {
+    pub years: i32,
+    pub months: i32,
+    pub days: i32,
+}
+
+impl Display for Period {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let units = [
+            (self.years, "an", "ans"),
+            (self.months, "mois", "mois"),
+            (self.days, "jour", "jours"),
+        ];
+
+        let parts: Vec<String> = units
+            .iter()
+            .filter(|(value, _, _)| *value != 0)
+            .map(|&(value, singular, plural)| {
+                let unit = if value.abs() == 1 { singular } else { plural };
+                format!("{} {}", value, unit)
+            })
+            .collect();
+
+        if parts.is_empty() {
+            write!(f, "0 jour")
+        } else {
+            write!(f, "{}", parts.join(", "))
+        }
+    }
+}
+
+impl Period {
+    /// Returns the period from `start` to `end`. If `end` precedes `start`,
+    /// every component of the result is negative.
+    ///
+    /// Whole months are counted first, then the remaining days. When the
+    /// start day does not exist in the month preceding `end` (e.g. starting on
+    /// the 30th and borrowing from the Complémentaires), it is clamped to the
+    /// last day of that month.
+    pub fn between<D: FrcDate>(start: &D, end: &D) -> Period {
+        if end.julian_day() < start.julian_day() {
+            let period = Period::between(end, start);
+
+            return Period {
+                years: -period.years,
+                months: -period.months,
+                days: -period.days,
+            };
+        }
+
+        let month_index = |date: &D| date.year() as i64 * 13 + date.month_int() as i64 - 1;
+
+        let mut months = month_index(end) - month_index(start);
+        let mut days = end.day() as i64 - start.day() as i64;
+
+        if months > 0 && days < 0 {
+            months -= 1;
+
+            // the last day of the month preceding `end` gives that month's length
+            let previous_month_len = end
+                .checked_sub_days(end.day().into())
+                .expect("a month precedes end since it is later than start")
+                .day() as i64;
+
+            days = previous_month_len - (start.day() as i64).min(previous_month_len)
+                + end.day() as i64;
+        }
+
+        Period {
+            years: (months / 13) as i32,
+            months: (months % 13) as i32,
+            days: days as i32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QDate, RommeDate};
+
+    #[test]
+    fn periods() {
+        let cases = &[
+            ((8, 2, 18), (8, 2, 18), (0, 0, 0)),
+            ((8, 2, 18), (8, 2, 20), (0, 0, 2)),
+            ((8, 2, 18), (10, 5, 22), (2, 3, 4)),
+            ((8, 2, 18), (9, 2, 17), (0, 12, 29)),
+            ((8, 12, 30), (9, 1, 3), (0, 1, 3)),
+            ((8, 12, 3), (9, 1, 2), (0, 1, 5)),
+            ((2, 11, 9), (8, 2, 18), (5, 4, 9)),
+            ((8, 13, 5), (9, 1, 1), (0, 0, 2)),
+            ((9, 13, 5), (10, 1, 1), (0, 0, 1)),
+        ];
+
+        for &((y1, m1, d1), (y2, m2, d2), (years, months, days)) in cases {
+            let start = RommeDate::from_frc(y1, m1, d1).unwrap();
+            let end = RommeDate::from_frc(y2, m2, d2).unwrap();
+            let expected = Period {
+                years,
+                months,
+                days,
+            };
+
+            assert_eq!(Period::between(&start, &end), expected);
+            assert_eq!(
+                Period::between(&end, &start),
+                Period {
+                    years: -years,
+                    months: -months,
+                    days: -days
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn borrows_leap_complementaires() {
+        let start = QDate::from_frc(3, 12, 30).unwrap();
+        let end = QDate::from_frc(4, 1, 3).unwrap();
+
+        assert_eq!(
+            Period::between(&start, &end),
+            Period {
+                years: 0,
+                months: 1,
+                days: 3
+            }
+        );
+
+        let start = QDate::from_frc(3, 13, 2).unwrap();
+        let end = QDate::from_frc(4, 1, 1).unwrap();
+
+        assert_eq!(
+            Period::between(&start, &end),
+            Period {
+                years: 0,
+                months: 0,
+                days: 5
+            }
+        );
+    }
+
+    #[test]
+    fn display() {
+        let period = Period {
+            years: 2,
+            months: 3,
+            days: 4,
+        };
+        assert_eq!(period.to_string(), "2 ans, 3 mois, 4 jours");
+
+        let period = Period {
+            years: 1,
+            months: 0,
+            days: 1,
+        };
+        assert_eq!(period.to_string(), "1 an, 1 jour");
+
+        assert_eq!(Period::default().to_string(), "0 jour");
+    }
+}
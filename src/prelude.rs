@@ -0,0 +1,20 @@
+//! Re-exports the FRC date types along with the [`FrcDate`] trait, so that its
+//! methods can be called on any implementation with a single import.
+
+pub use crate::astronomical_date::AstronomicalDate;
+pub use crate::date::{Date, DateError, DateResult, FrcDate, OverflowPolicy};
+pub use crate::date_time::FrcDateTime;
+pub use crate::day_kind::{DayKind, OrdinaryDay};
+pub use crate::decade::Decade;
+pub use crate::decimal_time::DecimalTime;
+pub use crate::delta_t::DeltaT;
+pub use crate::equinox::{autumn_equinox, new_year_day, NewYearDay, PARIS_MEAN_TIME};
+pub use crate::historical_date::HistoricalDate;
+pub use crate::leap_rule::LeapRule;
+pub use crate::period::Period;
+pub use crate::qdate::QDate;
+pub use crate::range::FrcDateRange;
+pub use crate::romme_date::RommeDate;
+pub use crate::rule_date::RuleDate;
+pub use crate::year::FrcYear;
+pub use crate::year_month::YearMonth;
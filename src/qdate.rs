@@ -1,16 +1,15 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use crate::{
     cal,
-    date::{DateResult, FrcDate},
-    numeral,
+    date::{self, Date, DateError, DateResult, FrcDate},
 };
 
-type JDN = i32;
+type Jdn = i32;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct QDate {
-    jdn: JDN,
+    jdn: Jdn,
     year: i32,
     month: u8,
     day: u8,
@@ -18,17 +17,22 @@ pub struct QDate {
 
 impl Display for QDate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let year = if self.year < 0 || self.year > (numeral::MAX_NUMERAL_VALUE as i32) {
-            format!("{}", self.year)
-        } else {
-            format!("{}", numeral::to_numeral(self.year as u16).unwrap())
-        };
+        Date::new(*self).fmt(f)
+    }
+}
 
-        write!(f, "{} {} An {}", self.day, self.month, year)
+impl FromStr for QDate {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> DateResult<QDate> {
+        s.parse::<Date<Self>>().map(Date::into_inner)
     }
 }
 
 impl FrcDate for QDate {
+    const MIN_JULIAN_DAY: i64 = QDate::MIN.jdn as i64;
+    const MAX_JULIAN_DAY: i64 = QDate::MAX.jdn as i64;
+
     fn month_int(&self) -> u8 {
         self.month
     }
@@ -41,26 +45,71 @@ impl FrcDate for QDate {
         self.year
     }
 
-    fn is_leap_year(&self) -> bool {
-        QDate::leap_year(self.year)
+    /// Returns whether `year` is a leap year according to the equinox table.
+    /// Years outside of the table are reported as regular years.
+    fn leap_year(year: i32) -> bool {
+        usize::try_from(i64::from(year) - i64::from(cal::START_YEAR))
+            .ok()
+            .and_then(|index| cal::LEAP_YEARS.get(index))
+            == Some(&1)
+    }
+
+    fn julian_day(&self) -> i64 {
+        self.jdn.into()
+    }
+
+    fn from_julian_day(jdn: i64) -> DateResult<Self> {
+        if !(Self::MIN_JULIAN_DAY..=Self::MAX_JULIAN_DAY).contains(&jdn) {
+            return Err(date::out_of_range::<Self>());
+        }
+
+        Ok(QDate::new(jdn as Jdn))
+    }
+
+    fn from_frc(year: i32, month: u8, day: u8) -> DateResult<Self> {
+        if !(QDate::MIN.year..=QDate::MAX.year).contains(&year) {
+            return Err(date::out_of_range::<Self>());
+        }
+
+        if !date::is_valid_day(month, day, QDate::leap_year(year)) {
+            return Err(DateError::InvalidDate);
+        }
+
+        Ok(QDate::new(QDate::to_julian_day(year, month, day)))
     }
 }
 
+date::impl_day_arithmetic!(QDate);
+
 impl QDate {
-    pub fn today_utc() -> Self {
+    /// The first date covered by the equinox table, 1 Vendémiaire of the year
+    /// -14991.
+    pub const MIN: QDate = QDate {
+        jdn: cal::START_JD,
+        year: cal::START_YEAR,
+        month: 1,
+        day: 1,
+    };
+
+    /// The last date covered by the equinox table, 5 Complémentaires of the
+    /// year 15398.
+    pub const MAX: QDate = QDate {
+        jdn: 7_999_825,
+        year: 15_398,
+        month: 13,
+        day: 5,
+    };
+
+    pub fn today_utc() -> DateResult<Self> {
         let today = time::OffsetDateTime::now_utc();
 
-        let jdn = today.to_julian_day();
-
-        QDate::new(jdn)
+        QDate::from_julian_day(today.to_julian_day().into())
     }
 
     pub fn today_local() -> DateResult<Self> {
         let today = time::OffsetDateTime::now_local()?;
 
-        let jdn = today.to_julian_day();
-
-        Ok(QDate::new(jdn))
+        QDate::from_julian_day(today.to_julian_day().into())
     }
 
     pub fn from_gregorian_date(year: i32, month: u8, day: u8) -> DateResult<Self> {
@@ -82,16 +131,22 @@ impl QDate {
 
         let date = time::Date::from_calendar_date(year, month as time::Month, day)?;
 
-        Ok(QDate::new(date.to_julian_day()))
+        QDate::from_julian_day(date.to_julian_day().into())
     }
 
     /// Returns the Julian Day for this date
-    pub fn jdn(&self) -> JDN {
+    pub fn jdn(&self) -> Jdn {
         self.jdn
     }
 
-    fn new(jdn: JDN) -> Self {
-        let (year, month, day) = QDate::from_julian_day(jdn);
+    /// Returns the Gregorian date corresponding to this date.
+    pub fn to_gregorian(&self) -> DateResult<time::Date> {
+        time::Date::from_julian_day(self.jdn).map_err(|_| DateError::GregorianOutOfRange)
+    }
+
+    /// Constructs the date from a Julian Day Number between `MIN` and `MAX`.
+    fn new(jdn: Jdn) -> Self {
+        let (year, month, day) = QDate::components_from_julian_day(jdn);
 
         Self {
             jdn,
@@ -101,7 +156,7 @@ impl QDate {
         }
     }
 
-    fn from_julian_day(day: JDN) -> (i32, u8, u8) {
+    fn components_from_julian_day(day: Jdn) -> (i32, u8, u8) {
         let mut low: i32 = 0;
         let mut high: i32 = 30391; // hard-coded length of leap year slice length
 
@@ -116,29 +171,22 @@ impl QDate {
             }
         }
 
-        let dd = day
-            - ((cal::START_JD as i32)
-                + (365 as i32) * (low as i32)
-                + (leaps_to_date[low as usize] as i32));
+        let dd = day - (cal::START_JD + 365 * low + (leaps_to_date[low as usize] as i32));
 
-        let year = cal::START_YEAR + (low as i32);
+        let year = cal::START_YEAR + low;
         let month = (dd / 30 + 1) as u8;
         let day = (dd % 30 + 1) as u8;
 
         (year, month, day)
     }
 
-    fn to_julian_day(year: i32, month: u8, day: u8) -> JDN {
+    fn to_julian_day(year: i32, month: u8, day: u8) -> Jdn {
         let dy = year - cal::START_YEAR;
         let dd: i32 = ((month as u32) * 30 + (day as u32) - 31) as i32;
 
         let leaps_to_date = cal::leaps_to_date();
 
-        cal::START_JD + 365 * dy + (leaps_to_date[dy as usize] as i32) + (dd as i32)
-    }
-
-    fn leap_year(year: i32) -> bool {
-        cal::LEAP_YEARS[(year - cal::START_YEAR) as usize] == 1
+        cal::START_JD + 365 * dy + (leaps_to_date[dy as usize] as i32) + dd
     }
 }
 
@@ -162,7 +210,7 @@ mod tests {
         ];
 
         for (jdn, frc_date) in dates {
-            assert_eq!(QDate::from_julian_day(jdn), frc_date);
+            assert_eq!(QDate::components_from_julian_day(jdn), frc_date);
         }
     }
 
@@ -235,4 +283,184 @@ mod tests {
         assert_eq!(date.day(), 1);
         assert_eq!(date.day_of_year(), 31);
     }
+
+    #[test]
+    fn day_arithmetic() {
+        let date = QDate::from_frc(233, 1, 30).unwrap();
+        assert_eq!(date.succ().unwrap(), QDate::from_frc(233, 2, 1).unwrap());
+        assert_eq!(date.succ().unwrap().pred().unwrap(), date);
+
+        let date = QDate::from_frc(3, 12, 30).unwrap();
+        assert_eq!(date.succ().unwrap(), QDate::from_frc(3, 13, 1).unwrap());
+        assert_eq!((date + 6).unwrap(), QDate::from_frc(3, 13, 6).unwrap());
+        assert_eq!((date + 7).unwrap(), QDate::from_frc(4, 1, 1).unwrap());
+
+        let date = QDate::from_frc(233, 1, 1).unwrap();
+        assert_eq!(date.pred().unwrap(), QDate::from_frc(232, 13, 5).unwrap());
+        assert_eq!(
+            (date - time::Duration::days(365)).unwrap(),
+            QDate::from_frc(232, 1, 1).unwrap()
+        );
+        assert_eq!(
+            (date + time::Duration::hours(47)).unwrap(),
+            QDate::from_frc(233, 1, 2).unwrap()
+        );
+        assert_eq!(date.checked_add_days(-30).unwrap(), (date - 30).unwrap());
+    }
+
+    #[test]
+    fn difference() {
+        let thermidor = QDate::from_frc(2, 11, 9).unwrap();
+        let brumaire = QDate::from_frc(8, 2, 18).unwrap();
+
+        assert_eq!(brumaire - thermidor, 1931);
+        assert_eq!(thermidor - brumaire, -1931);
+        assert_eq!(brumaire - brumaire, 0);
+    }
+
+    #[test]
+    fn arithmetic_out_of_range() {
+        let first = QDate::from_julian_day(cal::START_JD.into()).unwrap();
+        assert!(matches!(first.pred(), Err(DateError::OutOfRange { .. })));
+
+        let date = QDate::from_frc(233, 1, 1).unwrap();
+        assert!(matches!(
+            date + 10_000_000,
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            date.checked_sub_days(i64::MAX),
+            Err(DateError::OutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(
+            QDate::from_julian_day(QDate::MIN_JULIAN_DAY).unwrap(),
+            QDate::MIN
+        );
+        assert_eq!(
+            QDate::from_julian_day(QDate::MAX_JULIAN_DAY).unwrap(),
+            QDate::MAX
+        );
+        assert_eq!(QDate::from_frc(-14991, 1, 1).unwrap(), QDate::MIN);
+        assert_eq!(QDate::from_frc(15398, 13, 5).unwrap(), QDate::MAX);
+        assert_eq!(QDate::MAX.days_in_month(), 5);
+
+        assert!(matches!(
+            QDate::MAX.succ(),
+            Err(DateError::OutOfRange {
+                min: -3_099_887,
+                max: 7_999_825
+            })
+        ));
+        assert!(matches!(
+            QDate::from_julian_day(QDate::MIN_JULIAN_DAY - 1),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            QDate::from_frc(15399, 1, 1),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            QDate::from_frc(i32::MIN, 1, 1),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            QDate::from_frc(233, 13, 7),
+            Err(DateError::InvalidDate)
+        ));
+        assert!(QDate::from_gregorian_date(-9999, 1, 1).is_ok());
+        assert!(QDate::from_gregorian_date(9999, 12, 31).is_ok());
+        assert!(QDate::today_utc().is_ok());
+    }
+
+    #[test]
+    fn frc_conversion() {
+        let dates = vec![
+            ((8, 2, 18), 2378444),
+            ((2, 11, 9), 2376513),
+            ((3, 13, 6), 2376935),
+            ((233, 2, 1), 2460606),
+        ];
+
+        for ((year, month, day), jdn) in dates {
+            let date = QDate::from_frc(year, month, day).unwrap();
+            assert_eq!(date.jdn(), jdn);
+            assert_eq!(
+                (date.year(), date.month_int(), date.day()),
+                (year, month, day)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_frc_components() {
+        let dates = &[
+            (8, 0, 1),
+            (8, 14, 1),
+            (8, 2, 0),
+            (8, 2, 31),
+            (1, 13, 6),
+            (3, 13, 7),
+            (-20000, 1, 1),
+            (20000, 1, 1),
+        ];
+
+        for &(year, month, day) in dates {
+            assert!(
+                QDate::from_frc(year, month, day).is_err(),
+                "{}-{}-{} should be invalid",
+                year,
+                month,
+                day
+            );
+        }
+    }
+
+    #[test]
+    fn gregorian_conversion() {
+        let dates = vec![
+            ((1792, 9, 22), (1, 1, 1)),
+            ((1794, 7, 27), (2, 11, 9)),
+            ((1799, 11, 9), (8, 2, 18)),
+            ((1795, 9, 22), (3, 13, 6)),
+            ((2024, 10, 22), (233, 2, 1)),
+        ];
+
+        for ((year, month, day), expected) in dates {
+            let date = QDate::from_gregorian_date(year, month, day).unwrap();
+            assert_eq!((date.year(), date.month_int(), date.day()), expected);
+
+            let gregorian = date.to_gregorian().unwrap();
+            assert_eq!(
+                (gregorian.year(), gregorian.month() as u8, gregorian.day()),
+                (year, month, day)
+            );
+        }
+    }
+
+    #[test]
+    fn gregorian_round_trip() {
+        let mut date = time::Date::MIN;
+
+        while date < time::Date::MAX {
+            let qdate =
+                QDate::from_gregorian_date(date.year(), date.month() as u8, date.day()).unwrap();
+
+            assert_eq!(qdate.to_gregorian().unwrap(), date);
+
+            date = date.saturating_add(time::Duration::days(13));
+        }
+
+        assert!(matches!(
+            QDate::MIN.to_gregorian(),
+            Err(DateError::GregorianOutOfRange)
+        ));
+        assert!(matches!(
+            QDate::MAX.to_gregorian(),
+            Err(DateError::GregorianOutOfRange)
+        ));
+    }
 }
@@ -0,0 +1,214 @@
+use std::iter;
+
+use crate::{
+    date::{DateError, DateResult, FrcDate},
+    decade::Decade,
+    year::FrcYear,
+    year_month::YearMonth,
+};
+
+/// An inclusive range of FRC dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrcDateRange<D: FrcDate> {
+    start: D,
+    end: D,
+}
+
+impl<D: FrcDate + Copy> FrcDateRange<D> {
+    /// Constructs the range from `start` to `end`, both included. Fails with
+    /// [`DateError::InvalidDate`] if `end` precedes `start`.
+    pub fn new(start: D, end: D) -> DateResult<Self> {
+        if end.julian_day() < start.julian_day() {
+            return Err(DateError::InvalidDate);
+        }
+
+        Ok(FrcDateRange { start, end })
+    }
+
+    pub fn start(&self) -> D {
+        self.start
+    }
+
+    pub fn end(&self) -> D {
+        self.end
+    }
+
+    /// Returns the number of days in the range, counting both ends.
+    pub fn len_days(&self) -> i64 {
+        self.end.julian_day() - self.start.julian_day() + 1
+    }
+
+    pub fn contains(&self, date: &D) -> bool {
+        (self.start.julian_day()..=self.end.julian_day()).contains(&date.julian_day())
+    }
+
+    /// Returns whether the two ranges share at least one day.
+    pub fn overlaps(&self, other: &FrcDateRange<D>) -> bool {
+        self.start.julian_day() <= other.end.julian_day()
+            && other.start.julian_day() <= self.end.julian_day()
+    }
+
+    /// Returns the days shared by the two ranges, if any.
+    pub fn intersection(&self, other: &FrcDateRange<D>) -> Option<FrcDateRange<D>> {
+        let start = if self.start.julian_day() >= other.start.julian_day() {
+            self.start
+        } else {
+            other.start
+        };
+
+        let end = if self.end.julian_day() <= other.end.julian_day() {
+            self.end
+        } else {
+            other.end
+        };
+
+        FrcDateRange::new(start, end).ok()
+    }
+
+    /// Iterates over every day of the range.
+    pub fn days(&self) -> impl Iterator<Item = D> + '_ {
+        iter::successors(Some(self.start), |date| date.succ().ok())
+            .take_while(|date| date.julian_day() <= self.end.julian_day())
+    }
+
+    /// Iterates over every décade with at least one day in the range. A range
+    /// made up only of Complémentaires days holds no décade, and décades the
+    /// implementation only supports part of are left out.
+    pub fn decades(&self) -> impl Iterator<Item = Decade<D>> + '_ {
+        let first = match self.start.decade() {
+            Some(decade) => Some(decade),
+            None => self
+                .start
+                .year()
+                .checked_add(1)
+                .and_then(|year| Decade::from_decade_of_year(year, 1).ok()),
+        };
+
+        iter::successors(first, |decade| decade.next().ok())
+            .take_while(|decade| decade.first_day().julian_day() <= self.end.julian_day())
+    }
+
+    /// Iterates over every month with at least one day in the range, the
+    /// Complémentaires included. Months the implementation only supports part
+    /// of, such as Nivôse An XIV of [`HistoricalDate`](crate::HistoricalDate),
+    /// are left out.
+    pub fn months(&self) -> impl Iterator<Item = YearMonth<D>> + '_ {
+        iter::successors(YearMonth::of(&self.start).ok(), |month| month.next().ok())
+            .take_while(|month| month.first().julian_day() <= self.end.julian_day())
+    }
+
+    /// Iterates over every year with at least one day in the range, leaving
+    /// out years the implementation only supports part of.
+    pub fn years(&self) -> impl Iterator<Item = FrcYear<D>> + '_ {
+        (self.start.year()..=self.end.year()).filter_map(|year| FrcYear::new(year).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{month::Month, HistoricalDate, QDate, RommeDate};
+
+    fn span(start: (i32, u8, u8), end: (i32, u8, u8)) -> FrcDateRange<QDate> {
+        FrcDateRange::new(
+            QDate::from_frc(start.0, start.1, start.2).unwrap(),
+            QDate::from_frc(end.0, end.1, end.2).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn bounds() {
+        let range = span((232, 12, 25), (233, 1, 5));
+
+        assert_eq!(range.len_days(), 16);
+        assert!(range.contains(&QDate::from_frc(232, 13, 3).unwrap()));
+        assert!(range.contains(&QDate::from_frc(233, 1, 5).unwrap()));
+        assert!(!range.contains(&QDate::from_frc(233, 1, 6).unwrap()));
+        assert!(!range.contains(&QDate::from_frc(232, 12, 24).unwrap()));
+
+        assert!(FrcDateRange::new(range.end(), range.start()).is_err());
+
+        let single = FrcDateRange::new(range.start(), range.start()).unwrap();
+        assert_eq!(single.len_days(), 1);
+    }
+
+    #[test]
+    fn overlaps_and_intersection() {
+        let a = span((233, 1, 1), (233, 2, 10));
+        let b = span((233, 2, 10), (233, 3, 1));
+        let c = span((233, 2, 11), (233, 3, 1));
+
+        assert!(a.overlaps(&b));
+        assert!(b.overlaps(&a));
+        assert!(!a.overlaps(&c));
+        assert_eq!(a.intersection(&b), Some(span((233, 2, 10), (233, 2, 10))));
+        assert_eq!(a.intersection(&c), None);
+
+        let outer = span((233, 1, 1), (234, 1, 1));
+        assert_eq!(outer.intersection(&c), Some(c));
+        assert_eq!(c.intersection(&outer), Some(c));
+    }
+
+    #[test]
+    fn days() {
+        let range = span((232, 12, 29), (233, 1, 2));
+        let days: Vec<QDate> = range.days().collect();
+
+        assert_eq!(days.len() as i64, range.len_days());
+        assert_eq!(days[0], QDate::from_frc(232, 12, 29).unwrap());
+        assert_eq!(days[2], QDate::from_frc(232, 13, 1).unwrap());
+        assert_eq!(days[7], QDate::from_frc(233, 1, 1).unwrap());
+        assert_eq!(days[8], QDate::from_frc(233, 1, 2).unwrap());
+    }
+
+    #[test]
+    fn decades() {
+        let range = span((232, 12, 15), (233, 1, 11));
+        let decades: Vec<String> = range.decades().map(|decade| decade.to_string()).collect();
+
+        assert_eq!(decades, vec!["232-D35", "232-D36", "233-D01", "233-D02"]);
+
+        let complementaires = span((232, 13, 1), (232, 13, 5));
+        assert_eq!(complementaires.decades().count(), 0);
+    }
+
+    #[test]
+    fn months_and_years() {
+        let range = FrcDateRange::new(
+            RommeDate::from_frc(232, 12, 15).unwrap(),
+            RommeDate::from_frc(234, 1, 1).unwrap(),
+        )
+        .unwrap();
+
+        let months: Vec<YearMonth<RommeDate>> = range.months().collect();
+        assert_eq!(months.len(), 16);
+        assert_eq!(months[0], YearMonth::new(232, Month::Fructidor).unwrap());
+        assert_eq!(
+            months[1],
+            YearMonth::new(232, Month::Complémentaires).unwrap()
+        );
+        assert_eq!(months[15], YearMonth::new(234, Month::Vendémiaire).unwrap());
+
+        let years: Vec<i32> = range.years().map(|year| year.year()).collect();
+        assert_eq!(years, vec![232, 233, 234]);
+    }
+
+    #[test]
+    fn partly_supported_periods() {
+        let range = FrcDateRange::new(
+            HistoricalDate::from_frc(14, 3, 1).unwrap(),
+            HistoricalDate::MAX,
+        )
+        .unwrap();
+
+        let decades: Vec<String> = range.decades().map(|decade| decade.to_string()).collect();
+        assert_eq!(decades, vec!["14-D07", "14-D08", "14-D09", "14-D10"]);
+
+        // Nivôse and An XIV as a whole are only partly supported
+        let months: Vec<YearMonth<HistoricalDate>> = range.months().collect();
+        assert_eq!(months, vec![YearMonth::new(14, Month::Frimaire).unwrap()]);
+        assert_eq!(range.years().count(), 0);
+        assert_eq!(range.days().count(), 40);
+    }
+}
@@ -1,15 +1,22 @@
-use crate::date::{DateError, FrcDate};
+use std::{fmt::Display, str::FromStr};
 
-const DAYS_PER_400_YEARS: i32 = 365 * 400 + 97;
-const DAYS_PER_100_YEARS: i32 = 365 * 100 + 24;
-const DAYS_PER_4_YEARS: i32 = 365 * 4 + 1;
+use crate::date::{self, Date, DateError, FrcDate, EPOCH_JDN};
+
+const DAYS_PER_400_YEARS: i64 = 365 * 400 + 97;
+const DAYS_PER_100_YEARS: i64 = 365 * 100 + 24;
+const DAYS_PER_4_YEARS: i64 = 365 * 4 + 1;
 
 pub type DateResult = Result<RommeDate, DateError>;
 
-type RommeDelta = i32;
+type RommeDelta = i64;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 /// Represents an FRC date that adheres to the Romme Rule
+///
+/// Dates before 1 Vendémiaire An I are proleptic and numbered like
+/// astronomical years: the year before An I is year 0, the one before that
+/// year -1, and so on. The leap rule carries on unchanged, so year 0 is a
+/// leap year.
 pub struct RommeDate {
     days: RommeDelta,
     year: i32,
@@ -17,7 +24,24 @@ pub struct RommeDate {
     day: u8,
 }
 
+impl Display for RommeDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Date::new(*self).fmt(f)
+    }
+}
+
+impl FromStr for RommeDate {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> DateResult {
+        s.parse::<Date<Self>>().map(Date::into_inner)
+    }
+}
+
 impl FrcDate for RommeDate {
+    const MIN_JULIAN_DAY: i64 = EPOCH_JDN + days_before_year(i32::MIN as i64);
+    const MAX_JULIAN_DAY: i64 = EPOCH_JDN + days_before_year(i32::MAX as i64 + 1) - 1;
+
     fn month_int(&self) -> u8 {
         self.month
     }
@@ -28,30 +52,46 @@ impl FrcDate for RommeDate {
 
     fn year(&self) -> i32 {
         self.year
-            .try_into()
-            .expect("Cannot convert this year into an i32")
     }
 
-    fn is_leap_year(&self) -> bool {
-        self.year % 4 == 0 && (self.year % 100 != 0 || self.year % 400 == 0)
+    fn leap_year(year: i32) -> bool {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+
+    fn julian_day(&self) -> i64 {
+        EPOCH_JDN + self.days
+    }
+
+    fn from_julian_day(jdn: i64) -> DateResult {
+        if !(Self::MIN_JULIAN_DAY..=Self::MAX_JULIAN_DAY).contains(&jdn) {
+            return Err(date::out_of_range::<Self>());
+        }
+
+        Ok(RommeDate::new(jdn - EPOCH_JDN))
+    }
+
+    fn from_frc(year: i32, month: u8, day: u8) -> DateResult {
+        if !date::is_valid_day(month, day, RommeDate::leap_year(year)) {
+            return Err(DateError::InvalidDate);
+        }
+
+        Ok(RommeDate::new(RommeDate::to_days(year, month, day)))
     }
 }
 
+date::impl_day_arithmetic!(RommeDate);
+
 impl RommeDate {
     pub fn today_local() -> DateResult {
         let today = time::OffsetDateTime::now_local()?;
 
-        let delta = (today.date() - RommeDate::epoch()).whole_days();
-
-        Ok(RommeDate::new(delta.try_into().unwrap()))
+        RommeDate::from_julian_day(today.to_julian_day().into())
     }
 
-    pub fn today_utc() -> Self {
+    pub fn today_utc() -> DateResult {
         let today = time::OffsetDateTime::now_utc();
 
-        let delta = (today.date() - RommeDate::epoch()).whole_days();
-
-        RommeDate::new(delta.try_into().unwrap())
+        RommeDate::from_julian_day(today.to_julian_day().into())
     }
 
     pub fn from_georgian_date(year: i32, month: u8, day: u8) -> DateResult {
@@ -73,12 +113,24 @@ impl RommeDate {
 
         let date = time::Date::from_calendar_date(year, month as time::Month, day)?;
 
-        // TODO deal with i64 type properly
-        let delta = (date - RommeDate::epoch()).whole_days();
+        RommeDate::from_julian_day(date.to_julian_day().into())
+    }
+
+    /// Returns the Gregorian date corresponding to this date.
+    pub fn to_gregorian(&self) -> Result<time::Date, DateError> {
+        RommeDate::epoch()
+            .checked_add(time::Duration::days(self.days))
+            .ok_or(DateError::GregorianOutOfRange)
+    }
 
-        Ok(RommeDate::new(delta.try_into().unwrap()))
+    /// Returns the number of days since 1 Vendémiaire An I, negative for dates
+    /// before it.
+    pub fn days_since_epoch(&self) -> i64 {
+        self.days
     }
 
+    /// Constructs the date from a number of days since the epoch, negative
+    /// values falling before An I.
     fn new(days: RommeDelta) -> Self {
         let (year, month, day) = RommeDate::from_days(days);
 
@@ -91,12 +143,10 @@ impl RommeDate {
     }
 
     fn from_days(days: RommeDelta) -> (i32, u8, u8) {
-        let mut days = days;
-        let mut years = 0;
-
-        let days_per_400_years = days / DAYS_PER_400_YEARS;
-        years = 400 * days_per_400_years;
-        days -= DAYS_PER_400_YEARS * days_per_400_years;
+        // flooring keeps the remainder within the 400-year cycle for dates
+        // before the epoch, so the steps below only see non-negative values
+        let mut years = 400 * days.div_euclid(DAYS_PER_400_YEARS);
+        let mut days = days.rem_euclid(DAYS_PER_400_YEARS);
 
         let mut days_per_100_years = days / DAYS_PER_100_YEARS;
         days_per_100_years -= days_per_100_years >> 2;
@@ -134,12 +184,13 @@ impl RommeDate {
             days = days - begin + 1;
         }
 
-        // TODO remove unwrap
-        (
-            years.try_into().unwrap(),
-            month.try_into().unwrap(),
-            days.try_into().unwrap(),
-        )
+        // the year is within i32 for every day between MIN_JULIAN_DAY and
+        // MAX_JULIAN_DAY, the month is at most 13 and the day at most 30
+        (years as i32, month as u8, days as u8)
+    }
+
+    fn to_days(year: i32, month: u8, day: u8) -> RommeDelta {
+        days_before_year(year.into()) + (i64::from(month) - 1) * 30 + i64::from(day) - 1
     }
 
     fn epoch() -> time::Date {
@@ -147,9 +198,17 @@ impl RommeDate {
     }
 }
 
+/// Returns the number of days from the epoch to 1 Vendémiaire of `year`.
+const fn days_before_year(year: i64) -> RommeDelta {
+    let years = year - 1;
+
+    365 * years + years.div_euclid(4) - years.div_euclid(100) + years.div_euclid(400)
+}
+
 #[cfg(test)]
 mod tests {
     use super::RommeDate;
+    use crate::date::{DateError, FrcDate};
 
     #[test]
     fn day_conversion() {
@@ -181,4 +240,211 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn frc_conversion() {
+        let dates = vec![
+            ((1, 1, 1), 0),
+            ((8, 2, 18), 2603),
+            ((2, 11, 9), 673),
+            ((224, 13, 6), 81813),
+            ((225, 13, 5), 82178),
+            ((226, 1, 1), 82179),
+            ((233, 2, 24), 84789),
+        ];
+
+        for ((year, month, day), days) in dates {
+            let date = RommeDate::from_frc(year, month, day).unwrap();
+            assert_eq!(date.days, days);
+            assert_eq!(
+                (date.year(), date.month_int(), date.day()),
+                (year, month, day)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_frc_components() {
+        let dates = &[
+            (8, 0, 1),
+            (8, 14, 1),
+            (8, 2, 0),
+            (8, 2, 31),
+            (225, 13, 6),
+            (224, 13, 7),
+        ];
+
+        for &(year, month, day) in dates {
+            assert!(
+                RommeDate::from_frc(year, month, day).is_err(),
+                "{}-{}-{} should be invalid",
+                year,
+                month,
+                day
+            );
+        }
+    }
+
+    #[test]
+    fn day_arithmetic() {
+        let date = RommeDate::from_frc(233, 1, 30).unwrap();
+        assert_eq!(
+            date.succ().unwrap(),
+            RommeDate::from_frc(233, 2, 1).unwrap()
+        );
+        assert_eq!(date.succ().unwrap().pred().unwrap(), date);
+
+        let date = RommeDate::from_frc(224, 12, 30).unwrap();
+        assert_eq!(
+            date.succ().unwrap(),
+            RommeDate::from_frc(224, 13, 1).unwrap()
+        );
+        assert_eq!(
+            (date + 6).unwrap(),
+            RommeDate::from_frc(224, 13, 6).unwrap()
+        );
+        assert_eq!((date + 7).unwrap(), RommeDate::from_frc(225, 1, 1).unwrap());
+
+        let date = RommeDate::from_frc(226, 1, 1).unwrap();
+        assert_eq!(
+            date.pred().unwrap(),
+            RommeDate::from_frc(225, 13, 5).unwrap()
+        );
+        assert_eq!(
+            (date - time::Duration::days(365)).unwrap(),
+            RommeDate::from_frc(225, 1, 1).unwrap()
+        );
+        assert_eq!(
+            (date + time::Duration::hours(47)).unwrap(),
+            RommeDate::from_frc(226, 1, 2).unwrap()
+        );
+        assert_eq!(date.checked_add_days(-30).unwrap(), (date - 30).unwrap());
+    }
+
+    #[test]
+    fn difference() {
+        let thermidor = RommeDate::from_frc(2, 11, 9).unwrap();
+        let brumaire = RommeDate::from_frc(8, 2, 18).unwrap();
+
+        assert_eq!(brumaire - thermidor, 1930);
+        assert_eq!(thermidor - brumaire, -1930);
+        assert_eq!(brumaire - brumaire, 0);
+    }
+
+    #[test]
+    fn arithmetic_out_of_range() {
+        let date = RommeDate::from_frc(1, 1, 1).unwrap();
+        assert!(matches!(date - i64::MAX, Err(DateError::OutOfRange { .. })));
+        assert!(matches!(date + i64::MAX, Err(DateError::OutOfRange { .. })));
+        assert!(matches!(
+            date + time::Duration::MAX,
+            Err(DateError::OutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn bounds() {
+        let first = RommeDate::from_julian_day(RommeDate::MIN_JULIAN_DAY).unwrap();
+        assert_eq!(first, RommeDate::from_frc(i32::MIN, 1, 1).unwrap());
+        assert_eq!(first.days_since_epoch(), -784_352_296_671);
+        assert!(matches!(first.pred(), Err(DateError::OutOfRange { .. })));
+
+        let last = RommeDate::from_julian_day(RommeDate::MAX_JULIAN_DAY).unwrap();
+        assert_eq!(last, RommeDate::from_frc(i32::MAX, 13, 5).unwrap());
+        assert_eq!(last.days_since_epoch(), 784_352_295_938);
+        assert!(matches!(
+            last.succ(),
+            Err(DateError::OutOfRange {
+                min: -784_349_920_831,
+                max: 784_354_671_778
+            })
+        ));
+        assert!(matches!(
+            first.checked_add_days(i64::MAX),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            RommeDate::from_julian_day(i64::MIN),
+            Err(DateError::OutOfRange { .. })
+        ));
+
+        let gregorian_min = time::Date::MIN;
+        let date = RommeDate::from_julian_day(gregorian_min.to_julian_day().into()).unwrap();
+        assert_eq!(date.to_gregorian().unwrap(), gregorian_min);
+        assert!(matches!(
+            first.to_gregorian(),
+            Err(DateError::GregorianOutOfRange)
+        ));
+        assert!(RommeDate::today_utc().is_ok());
+    }
+
+    #[test]
+    fn before_epoch() {
+        let dates = vec![
+            (0, (1, 1, 1)),
+            (-1, (0, 13, 6)),
+            (-6, (0, 13, 1)),
+            (-7, (0, 12, 30)),
+            (-366, (0, 1, 1)),
+            (-367, (-1, 13, 5)),
+            (-1166, (-3, 10, 26)),
+            (-1461, (-3, 1, 1)),
+            (-146_097, (-399, 1, 1)),
+            (-146_098, (-400, 13, 6)),
+        ];
+
+        for (days, (year, month, day)) in dates {
+            assert_eq!(RommeDate::from_days(days), (year, month, day));
+
+            let date = RommeDate::from_frc(year, month, day).unwrap();
+            assert_eq!(date.days, days);
+            assert_eq!(date.succ().unwrap().days, days + 1);
+            assert_eq!(date.pred().unwrap().days, days - 1);
+        }
+
+        assert!(RommeDate::leap_year(0));
+        assert!(!RommeDate::leap_year(-1));
+        assert!(RommeDate::leap_year(-4));
+        assert!(!RommeDate::leap_year(-100));
+        assert!(RommeDate::leap_year(-400));
+        assert!(RommeDate::from_frc(-1, 13, 6).is_err());
+    }
+
+    #[test]
+    fn gregorian_conversion() {
+        let dates = vec![
+            ((1789, 7, 14), (-3, 10, 26)),
+            ((1792, 9, 21), (0, 13, 6)),
+            ((1792, 9, 22), (1, 1, 1)),
+            ((1799, 11, 8), (8, 2, 18)),
+            ((2016, 9, 21), (224, 13, 6)),
+            ((2016, 9, 22), (225, 1, 1)),
+            ((2024, 11, 14), (233, 2, 24)),
+        ];
+
+        for ((year, month, day), expected) in dates {
+            let date = RommeDate::from_georgian_date(year, month, day).unwrap();
+            assert_eq!((date.year(), date.month_int(), date.day()), expected);
+
+            let gregorian = date.to_gregorian().unwrap();
+            assert_eq!(
+                (gregorian.year(), gregorian.month() as u8, gregorian.day()),
+                (year, month, day)
+            );
+        }
+    }
+
+    #[test]
+    fn gregorian_round_trip() {
+        let mut date = time::Date::MIN;
+
+        while date < time::Date::MAX {
+            let romme =
+                RommeDate::from_georgian_date(date.year(), date.month() as u8, date.day()).unwrap();
+
+            assert_eq!(romme.to_gregorian().unwrap(), date);
+
+            date = date.saturating_add(time::Duration::days(13));
+        }
+    }
 }
@@ -0,0 +1,237 @@
+use std::{fmt::Display, marker::PhantomData, str::FromStr};
+
+use crate::{
+    date::{self, Date, DateError, DateResult, FrcDate, EPOCH_JDN},
+    leap_rule::LeapRule,
+};
+
+/// Number of days supported on either side of the epoch, keeping the year
+/// within an `i32` under any rule.
+const MAX_DAYS: i64 = 100_000_000_000;
+
+/// An FRC date whose leap years follow the rule `R`, starting on 22 September
+/// 1792 like [`RommeDate`](crate::RommeDate). Dates before An I are
+/// proleptic, the year before An I being year 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuleDate<R: LeapRule> {
+    days: i64,
+    year: i32,
+    month: u8,
+    day: u8,
+    rule: PhantomData<R>,
+}
+
+impl<R: LeapRule> Display for RuleDate<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Date::new(*self).fmt(f)
+    }
+}
+
+impl<R: LeapRule> FromStr for RuleDate<R> {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> DateResult<Self> {
+        s.parse::<Date<Self>>().map(Date::into_inner)
+    }
+}
+
+impl<R: LeapRule> FrcDate for RuleDate<R> {
+    const MIN_JULIAN_DAY: i64 = EPOCH_JDN - MAX_DAYS;
+    const MAX_JULIAN_DAY: i64 = EPOCH_JDN + MAX_DAYS;
+
+    fn month_int(&self) -> u8 {
+        self.month
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    fn leap_year(year: i32) -> bool {
+        R::is_leap(year)
+    }
+
+    fn julian_day(&self) -> i64 {
+        EPOCH_JDN + self.days
+    }
+
+    fn from_julian_day(jdn: i64) -> DateResult<Self> {
+        if !(Self::MIN_JULIAN_DAY..=Self::MAX_JULIAN_DAY).contains(&jdn) {
+            return Err(date::out_of_range::<Self>());
+        }
+
+        Ok(RuleDate::new(jdn - EPOCH_JDN))
+    }
+
+    fn from_frc(year: i32, month: u8, day: u8) -> DateResult<Self> {
+        if !date::is_valid_day(month, day, R::is_leap(year)) {
+            return Err(DateError::InvalidDate);
+        }
+
+        let days = R::days_before_year(year) + (i64::from(month) - 1) * 30 + i64::from(day) - 1;
+
+        Self::from_julian_day(EPOCH_JDN + days)
+    }
+}
+
+date::impl_day_arithmetic!(RuleDate<R: LeapRule>);
+
+impl<R: LeapRule> RuleDate<R> {
+    pub fn from_gregorian_date(year: i32, month: u8, day: u8) -> DateResult<Self> {
+        let month = time::Month::try_from(month).map_err(|_| DateError::InvalidDate)?;
+        let date = time::Date::from_calendar_date(year, month, day)?;
+
+        Self::from_julian_day(date.to_julian_day().into())
+    }
+
+    /// Returns the Gregorian date corresponding to this date.
+    pub fn to_gregorian(&self) -> Result<time::Date, DateError> {
+        let jdn = i32::try_from(self.julian_day()).map_err(|_| DateError::GregorianOutOfRange)?;
+
+        time::Date::from_julian_day(jdn).map_err(|_| DateError::GregorianOutOfRange)
+    }
+
+    /// Returns the number of days since 1 Vendémiaire An I, negative for dates
+    /// before it.
+    pub fn days_since_epoch(&self) -> i64 {
+        self.days
+    }
+
+    /// Constructs the date from a number of days since the epoch within
+    /// `MAX_DAYS`.
+    fn new(days: i64) -> Self {
+        let year = RuleDate::<R>::year_of(days);
+        let day_of_year = days - R::days_before_year(year);
+
+        Self {
+            days,
+            year,
+            // the day of the year is below 366
+            month: (day_of_year / 30 + 1) as u8,
+            day: (day_of_year % 30 + 1) as u8,
+            rule: PhantomData,
+        }
+    }
+
+    /// Returns the year containing the day `days` days after the epoch.
+    fn year_of(days: i64) -> i32 {
+        // every year has 365 or 366 days, so stepping by the distance in
+        // 366-day years never overshoots
+        let mut year = 1;
+
+        loop {
+            let start = R::days_before_year(year);
+
+            if days < start {
+                year -= ((start - days) / 366).max(1) as i32;
+            } else if days >= R::days_before_year(year + 1) {
+                year += ((days - start) / 366).max(1) as i32;
+            } else {
+                return year;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RuleDate;
+    use crate::{
+        date::{DateError, FrcDate},
+        leap_rule::{Delambre, LeapRule, Romme, Romme4000, VonMädler},
+        RommeDate,
+    };
+
+    fn check_round_trip<R: LeapRule>() {
+        let mut days = -2_000_000;
+
+        while days < 2_000_000 {
+            let date = RuleDate::<R>::new(days);
+            let from_frc = RuleDate::<R>::from_frc(date.year(), date.month_int(), date.day());
+
+            assert_eq!(from_frc.unwrap().days, days);
+            assert!(date.day() <= date.days_in_month());
+
+            days += 997;
+        }
+
+        for year in [-4000, -1, 0, 1, 128, 4000] {
+            let last = RuleDate::<R>::from_frc(year, 1, 1).unwrap().pred().unwrap();
+            let complementaires = if R::is_leap(year - 1) { 6 } else { 5 };
+
+            assert_eq!(
+                (last.year(), last.month_int(), last.day()),
+                (year - 1, 13, complementaires)
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        check_round_trip::<Romme>();
+        check_round_trip::<Romme4000>();
+        check_round_trip::<Delambre>();
+        check_round_trip::<VonMädler>();
+    }
+
+    #[test]
+    fn matches_romme_date() {
+        let mut days = -200_000;
+
+        while days < 2_000_000 {
+            let date = RuleDate::<Romme>::from_julian_day(2_375_840 + days).unwrap();
+            let romme = RommeDate::from_julian_day(2_375_840 + days).unwrap();
+
+            assert_eq!(
+                (date.year(), date.month_int(), date.day()),
+                (romme.year(), romme.month_int(), romme.day())
+            );
+
+            days += 13;
+        }
+    }
+
+    #[test]
+    fn rules_diverge() {
+        let romme = RuleDate::<Romme>::from_frc(4001, 1, 1).unwrap();
+        let romme_4000 = RuleDate::<Romme4000>::from_frc(4001, 1, 1).unwrap();
+        assert_eq!(romme.days_since_epoch() - romme_4000.days_since_epoch(), 1);
+
+        // the sextile day of year III fell on 22 September 1795
+        let sextile = RuleDate::<Delambre>::from_frc(3, 13, 6).unwrap();
+        assert_eq!(
+            sextile.to_gregorian().unwrap(),
+            time::Date::from_calendar_date(1795, time::Month::September, 22).unwrap()
+        );
+        assert!(RuleDate::<Romme>::from_frc(3, 13, 6).is_err());
+
+        // von Mädler keeps years 100 and 200 as leap years but drops 128, so
+        // the year starts a day later than under Romme's rule
+        let madler = RuleDate::<VonMädler>::from_gregorian_date(2024, 11, 14).unwrap();
+        assert_eq!(madler.to_string(), "23 Brumaire An CCXXXIII");
+        assert_eq!(
+            "23 Brumaire An CCXXXIII"
+                .parse::<RuleDate<VonMädler>>()
+                .unwrap(),
+            madler
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let last = RuleDate::<VonMädler>::from_julian_day(RuleDate::<VonMädler>::MAX_JULIAN_DAY);
+        assert!(matches!(
+            last.unwrap().succ(),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            RuleDate::<Romme>::from_frc(i32::MAX, 1, 1),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(RuleDate::<Delambre>::from_julian_day(i64::MIN).is_err());
+    }
+}
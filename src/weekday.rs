@@ -1,12 +1,14 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
-#[derive(Debug, Clone, PartialEq)]
+use crate::date::DateError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Weekday {
     Complimentary(Complimentary),
     Ordinary(Ordinary),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Complimentary {
     Vertu = 1,
     Génie = 2,
@@ -34,21 +36,36 @@ impl Display for Complimentary {
     }
 }
 
-impl Complimentary {
-    pub(crate) fn day_of_week(n: i32) -> Complimentary {
+impl FromStr for Complimentary {
+    type Err = DateError;
+
+    /// Parses the name of a fête as printed by `Display`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        (1..=6)
+            .filter_map(|n| Complimentary::try_from(n).ok())
+            .find(|day| day.to_string().to_lowercase() == s.to_lowercase())
+            .ok_or(DateError::ParseError)
+    }
+}
+
+impl TryFrom<u8> for Complimentary {
+    type Error = DateError;
+
+    /// Returns the fête of the `n`th day of the Complémentaires.
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
         match n {
-            1 => Complimentary::Vertu,
-            2 => Complimentary::Génie,
-            3 => Complimentary::Travail,
-            4 => Complimentary::lOpinion,
-            5 => Complimentary::Récompenses,
-            6 => Complimentary::Révolution,
-            _ => unreachable!("invalid value passed to day of week"),
+            1 => Ok(Complimentary::Vertu),
+            2 => Ok(Complimentary::Génie),
+            3 => Ok(Complimentary::Travail),
+            4 => Ok(Complimentary::lOpinion),
+            5 => Ok(Complimentary::Récompenses),
+            6 => Ok(Complimentary::Révolution),
+            _ => Err(DateError::InvalidDate),
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Ordinary {
     Primidi = 1,
     Duodi = 2,
@@ -83,20 +100,35 @@ impl Display for Ordinary {
     }
 }
 
-impl Ordinary {
-    pub(crate) fn day_of_week(n: i32) -> Ordinary {
+impl FromStr for Ordinary {
+    type Err = DateError;
+
+    /// Parses the name of a day of the décade, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        (1..=10)
+            .filter_map(|n| Ordinary::try_from(n).ok())
+            .find(|day| day.to_string() == s.to_lowercase())
+            .ok_or(DateError::ParseError)
+    }
+}
+
+impl TryFrom<u8> for Ordinary {
+    type Error = DateError;
+
+    /// Returns the `n`th day of the décade.
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
         match n {
-            1 => Ordinary::Primidi,
-            2 => Ordinary::Duodi,
-            3 => Ordinary::Tridi,
-            4 => Ordinary::Quartidi,
-            5 => Ordinary::Quintidi,
-            6 => Ordinary::Sextidi,
-            7 => Ordinary::Septidi,
-            8 => Ordinary::Octidi,
-            9 => Ordinary::Nonidi,
-            10 => Ordinary::Décadi,
-            _ => unreachable!("invalid value passed {:?}", n),
+            1 => Ok(Ordinary::Primidi),
+            2 => Ok(Ordinary::Duodi),
+            3 => Ok(Ordinary::Tridi),
+            4 => Ok(Ordinary::Quartidi),
+            5 => Ok(Ordinary::Quintidi),
+            6 => Ok(Ordinary::Sextidi),
+            7 => Ok(Ordinary::Septidi),
+            8 => Ok(Ordinary::Octidi),
+            9 => Ok(Ordinary::Nonidi),
+            10 => Ok(Ordinary::Décadi),
+            _ => Err(DateError::InvalidDate),
         }
     }
 }
@@ -0,0 +1,207 @@
+use std::{fmt::Display, marker::PhantomData};
+
+use crate::{
+    date::{self, DateError, DateResult, FrcDate},
+    month::Month,
+    year_month::YearMonth,
+};
+
+/// A year of the FRC. The FRC implementation `D` decides which years are leap
+/// years and when each year begins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrcYear<D: FrcDate> {
+    year: i32,
+    date: PhantomData<D>,
+}
+
+impl<D: FrcDate> Display for FrcYear<D> {
+    /// Writes the year as in a date, e.g. "An CCXXXIII".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "An {}", date::format_year(self.year))
+    }
+}
+
+impl<D: FrcDate> FrcYear<D> {
+    /// Constructs the year, failing if any of its days is outside of the
+    /// range supported by the implementation.
+    pub fn new(year: i32) -> DateResult<Self> {
+        let year = FrcYear {
+            year,
+            date: PhantomData,
+        };
+
+        D::from_frc(year.year, 1, 1)?;
+        D::from_frc(year.year, 13, year.complementary_days_len())?;
+
+        Ok(year)
+    }
+
+    /// Returns the year containing `date`, failing if the implementation only
+    /// supports part of it.
+    pub fn of(date: &D) -> DateResult<Self> {
+        FrcYear::new(date.year())
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn is_leap(&self) -> bool {
+        D::leap_year(self.year)
+    }
+
+    /// Returns the number of days in the year: 366 in leap years, 365
+    /// otherwise.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u16 {
+        if self.is_leap() {
+            366
+        } else {
+            365
+        }
+    }
+
+    /// Returns 1 Vendémiaire of this year.
+    pub fn first_day(&self) -> D {
+        self.nth_day(1, 1)
+    }
+
+    /// Returns the last day of the Complémentaires of this year.
+    pub fn last_day(&self) -> D {
+        self.nth_day(13, self.complementary_days_len())
+    }
+
+    /// Returns the Gregorian date on which this year begins.
+    pub fn gregorian_start(&self) -> DateResult<time::Date> {
+        let jdn = i32::try_from(self.first_day().julian_day())
+            .map_err(|_| DateError::GregorianOutOfRange)?;
+
+        Ok(time::Date::from_julian_day(jdn)?)
+    }
+
+    /// Iterates over the months of the year, ending with the Complémentaires.
+    pub fn months(&self) -> impl Iterator<Item = YearMonth<D>> + '_ {
+        (1..=13).map(|month| {
+            let month = Month::try_from(month).expect("months are numbered 1 to 13");
+
+            YearMonth::new(self.year, month).expect("the year is supported")
+        })
+    }
+
+    /// Iterates over the five or six days of the Complémentaires.
+    pub fn complementary_days(&self) -> impl Iterator<Item = D> + '_ {
+        (1..=self.complementary_days_len()).map(|day| self.nth_day(13, day))
+    }
+
+    fn complementary_days_len(&self) -> u8 {
+        if self.is_leap() {
+            6
+        } else {
+            5
+        }
+    }
+
+    fn nth_day(&self, month: u8, day: u8) -> D {
+        D::from_frc(self.year, month, day)
+            .expect("every day of a year exists once the year is supported")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HistoricalDate, QDate, RommeDate};
+
+    #[test]
+    fn year_facts() {
+        let year = FrcYear::<QDate>::new(233).unwrap();
+
+        assert!(!year.is_leap());
+        assert_eq!(year.len(), 365);
+        assert_eq!(year.first_day(), QDate::from_frc(233, 1, 1).unwrap());
+        assert_eq!(year.last_day(), QDate::from_frc(233, 13, 5).unwrap());
+        assert_eq!(
+            year.gregorian_start().unwrap(),
+            time::Date::from_calendar_date(2024, time::Month::September, 22).unwrap()
+        );
+        assert_eq!(year.to_string(), "An CCXXXIII");
+
+        let year = FrcYear::<QDate>::new(3).unwrap();
+        assert!(year.is_leap());
+        assert_eq!(year.len(), 366);
+        assert_eq!(year.last_day(), QDate::from_frc(3, 13, 6).unwrap());
+        assert_eq!(
+            year.last_day().succ().unwrap(),
+            FrcYear::<QDate>::new(4).unwrap().first_day()
+        );
+    }
+
+    #[test]
+    fn rules_differ() {
+        let romme = FrcYear::<RommeDate>::new(240).unwrap();
+        let quantum = FrcYear::<QDate>::new(240).unwrap();
+
+        assert!(romme.is_leap());
+        assert!(!quantum.is_leap());
+        assert_eq!(
+            romme.gregorian_start().unwrap(),
+            time::Date::from_calendar_date(2031, time::Month::September, 22).unwrap()
+        );
+        assert_eq!(
+            quantum.gregorian_start().unwrap(),
+            time::Date::from_calendar_date(2031, time::Month::September, 23).unwrap()
+        );
+    }
+
+    #[test]
+    fn iterators() {
+        let year = FrcYear::<RommeDate>::new(232).unwrap();
+
+        let months: Vec<YearMonth<RommeDate>> = year.months().collect();
+        assert_eq!(months.len(), 13);
+        assert_eq!(months[0].month(), Month::Vendémiaire);
+        assert_eq!(months[12].month(), Month::Complémentaires);
+        assert_eq!(months[12].len(), 6);
+
+        let days: Vec<RommeDate> = year.complementary_days().collect();
+        assert_eq!(days.len(), 6);
+        assert_eq!(days[0], RommeDate::from_frc(232, 13, 1).unwrap());
+        assert_eq!(days[5], RommeDate::from_frc(232, 13, 6).unwrap());
+        assert_eq!(
+            FrcYear::<RommeDate>::new(233)
+                .unwrap()
+                .complementary_days()
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(FrcYear::<QDate>::new(20_000).is_err());
+        assert!(FrcYear::<QDate>::new(-20_000).is_err());
+        assert!(FrcYear::<RommeDate>::new(0).unwrap().is_leap());
+        assert_eq!(
+            FrcYear::of(&QDate::from_frc(8, 2, 18).unwrap()).unwrap(),
+            FrcYear::<QDate>::new(8).unwrap()
+        );
+    }
+
+    #[test]
+    fn partly_supported_year() {
+        // An XIV of the historical calendar ends on 10 Nivôse
+        assert!(matches!(
+            FrcYear::<HistoricalDate>::new(14),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(FrcYear::of(&HistoricalDate::MAX).is_err());
+
+        let year = FrcYear::<HistoricalDate>::new(13).unwrap();
+        assert_eq!(
+            year.last_day(),
+            HistoricalDate::from_frc(13, 13, 5).unwrap()
+        );
+        assert_eq!(year.months().count(), 13);
+        assert_eq!(year.complementary_days().count(), 5);
+    }
+}
@@ -0,0 +1,198 @@
+use std::{fmt::Display, marker::PhantomData};
+
+use crate::{
+    date::{self, DateResult, FrcDate},
+    month::Month,
+};
+
+/// A month of a given FRC year. The Complémentaires are treated as a
+/// thirteenth month of five or six days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YearMonth<D: FrcDate> {
+    year: i32,
+    month: Month,
+    date: PhantomData<D>,
+}
+
+impl<D: FrcDate> Display for YearMonth<D> {
+    /// Writes the month and year, e.g. "Brumaire An CCXXXIII".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} An {}", self.month, date::format_year(self.year))
+    }
+}
+
+impl<D: FrcDate> YearMonth<D> {
+    /// Constructs the month, failing if any of its days is outside of the
+    /// range supported by the implementation.
+    pub fn new(year: i32, month: Month) -> DateResult<Self> {
+        let year_month = YearMonth {
+            year,
+            month,
+            date: PhantomData,
+        };
+
+        D::from_frc(year, month.value(), 1)?;
+        D::from_frc(year, month.value(), year_month.len())?;
+
+        Ok(year_month)
+    }
+
+    /// Returns the month containing `date`, failing if the implementation
+    /// only supports part of it.
+    pub fn of(date: &D) -> DateResult<Self> {
+        YearMonth::new(date.year(), date.month())
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> Month {
+        self.month
+    }
+
+    /// Returns the number of days in the month: 30, or 5 or 6 for the
+    /// Complémentaires depending on whether the year is a leap year.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u8 {
+        match self.month {
+            Month::Complémentaires if D::leap_year(self.year) => 6,
+            Month::Complémentaires => 5,
+            _ => 30,
+        }
+    }
+
+    pub fn first(&self) -> D {
+        self.nth_day(1)
+    }
+
+    pub fn last(&self) -> D {
+        self.nth_day(self.len())
+    }
+
+    /// Iterates over every day of the month.
+    pub fn days(&self) -> impl Iterator<Item = D> + '_ {
+        (1..=self.len()).map(|day| self.nth_day(day))
+    }
+
+    /// Returns the following month, moving on to Vendémiaire of the next year
+    /// after the Complémentaires.
+    pub fn next(&self) -> DateResult<Self> {
+        match self.month {
+            Month::Complémentaires => {
+                let year = self
+                    .year
+                    .checked_add(1)
+                    .ok_or_else(date::out_of_range::<D>)?;
+                YearMonth::new(year, self.month.next_month())
+            }
+            _ => YearMonth::new(self.year, self.month.next_month()),
+        }
+    }
+
+    /// Returns the preceding month, moving back to the Complémentaires of the
+    /// previous year before Vendémiaire.
+    pub fn prev(&self) -> DateResult<Self> {
+        match self.month {
+            Month::Vendémiaire => {
+                let year = self
+                    .year
+                    .checked_sub(1)
+                    .ok_or_else(date::out_of_range::<D>)?;
+                YearMonth::new(year, self.month.previous_month())
+            }
+            _ => YearMonth::new(self.year, self.month.previous_month()),
+        }
+    }
+
+    /// Returns whether `date` falls within this month.
+    pub fn contains(&self, date: &D) -> bool {
+        date.year() == self.year && date.month() == self.month
+    }
+
+    fn nth_day(&self, day: u8) -> D {
+        D::from_frc(self.year, self.month.value(), day)
+            .expect("every day of a month exists once its year is supported")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{date::DateError, HistoricalDate, QDate, RommeDate};
+
+    #[test]
+    fn lengths() {
+        let brumaire = YearMonth::<QDate>::new(233, Month::Brumaire).unwrap();
+        assert_eq!(brumaire.len(), 30);
+        assert_eq!(brumaire.first(), QDate::from_frc(233, 2, 1).unwrap());
+        assert_eq!(brumaire.last(), QDate::from_frc(233, 2, 30).unwrap());
+        assert_eq!(brumaire.days().count(), 30);
+        assert_eq!(brumaire.to_string(), "Brumaire An CCXXXIII");
+
+        let regular = YearMonth::<QDate>::new(233, Month::Complémentaires).unwrap();
+        assert_eq!(regular.len(), 5);
+        assert_eq!(regular.last(), QDate::from_frc(233, 13, 5).unwrap());
+
+        let leap = YearMonth::<QDate>::new(3, Month::Complémentaires).unwrap();
+        assert_eq!(leap.len(), 6);
+        assert_eq!(leap.days().last(), Some(QDate::from_frc(3, 13, 6).unwrap()));
+    }
+
+    #[test]
+    fn navigation() {
+        let complementaires = YearMonth::<RommeDate>::new(232, Month::Complémentaires).unwrap();
+        let vendemiaire = complementaires.next().unwrap();
+
+        assert_eq!(vendemiaire.year(), 233);
+        assert_eq!(vendemiaire.month(), Month::Vendémiaire);
+        assert_eq!(vendemiaire.prev().unwrap(), complementaires);
+        assert_eq!(complementaires.last().succ().unwrap(), vendemiaire.first());
+
+        let mut month = YearMonth::<RommeDate>::new(232, Month::Fructidor).unwrap();
+        for _ in 0..13 {
+            month = month.next().unwrap();
+        }
+        assert_eq!(month, YearMonth::new(233, Month::Fructidor).unwrap());
+
+        let first = YearMonth::<RommeDate>::new(1, Month::Vendémiaire).unwrap();
+        assert_eq!(
+            first.prev().unwrap(),
+            YearMonth::new(0, Month::Complémentaires).unwrap()
+        );
+
+        let first = YearMonth::<QDate>::new(-14991, Month::Vendémiaire).unwrap();
+        assert!(first.prev().is_err());
+    }
+
+    #[test]
+    fn containment() {
+        let month = YearMonth::<QDate>::new(8, Month::Brumaire).unwrap();
+
+        assert!(month.contains(&QDate::from_frc(8, 2, 18).unwrap()));
+        assert!(!month.contains(&QDate::from_frc(9, 2, 18).unwrap()));
+        assert!(!month.contains(&QDate::from_frc(8, 3, 1).unwrap()));
+        assert!(month.days().all(|day| month.contains(&day)));
+        assert_eq!(
+            YearMonth::of(&QDate::from_frc(8, 2, 18).unwrap()).unwrap(),
+            month
+        );
+    }
+
+    #[test]
+    fn partly_supported_month() {
+        // the historical calendar ends on 10 Nivôse An XIV
+        assert!(matches!(
+            YearMonth::<HistoricalDate>::new(14, Month::Nivôse),
+            Err(DateError::OutOfRange { .. })
+        ));
+        assert!(YearMonth::of(&HistoricalDate::MAX).is_err());
+
+        let frimaire = YearMonth::<HistoricalDate>::new(14, Month::Frimaire).unwrap();
+        assert_eq!(
+            frimaire.last(),
+            HistoricalDate::from_frc(14, 3, 30).unwrap()
+        );
+        assert!(frimaire.next().is_err());
+    }
+}